@@ -1,9 +1,14 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 use rayon::prelude::*;
+use serde::Deserialize;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::path::Path;
+use std::sync::RwLock;
 use strsim::jaro_winkler;
+use unicode_normalization::UnicodeNormalization;
 
 /// Input data for a single bibliographic item
 #[derive(Debug, FromPyObject)]
@@ -166,23 +171,149 @@ const ACADEMIC_REVIEW_PREFIXES: &[&str] = &[
     "responses to",
 ];
 
+/// Caller-supplied academic-prefix gate phrases, loaded from a scoring config
+/// via [`set_academic_prefixes`]. When unset, the compiled-in
+/// [`ACADEMIC_REVIEW_PREFIXES`] list is used.
+static CUSTOM_ACADEMIC_PREFIXES: RwLock<Option<Vec<String>>> = RwLock::new(None);
+
 /// Check if a title starts with an academic review/response prefix
 fn has_academic_prefix(title: &str) -> bool {
     let normalized = title.to_lowercase();
     let trimmed = normalized.trim();
+    if let Ok(guard) = CUSTOM_ACADEMIC_PREFIXES.read() {
+        if let Some(ref prefixes) = *guard {
+            return prefixes.iter().any(|p| trimmed.starts_with(p.as_str()));
+        }
+    }
     ACADEMIC_REVIEW_PREFIXES
         .iter()
         .any(|prefix| trimmed.starts_with(prefix))
 }
 
-/// Normalize text: lowercase and collapse whitespace
-fn normalize(s: &str) -> String {
-    s.to_lowercase()
+/// Fold a full-width / half-width compatibility character to its ASCII form.
+/// Covers the full-width ASCII block (digits, letters, punctuation) and the
+/// ideographic space, so "２０２４" normalizes to "2024" before scoring.
+fn fold_fullwidth(c: char) -> char {
+    match c as u32 {
+        0xFF01..=0xFF5E => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        0x3000 => ' ',
+        _ => c,
+    }
+}
+
+/// Unicode combining marks — NFD decomposition leaves these behind once an
+/// accented letter is split into base + mark, so dropping them folds the accent.
+fn is_combining_mark(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036F |   // Combining Diacritical Marks
+        0x1AB0..=0x1AFF |   // Combining Diacritical Marks Extended
+        0x1DC0..=0x1DFF |   // Combining Diacritical Marks Supplement
+        0x20D0..=0x20FF |   // Combining Diacritical Marks for Symbols
+        0xFE20..=0xFE2F     // Combining Half Marks
+    )
+}
+
+/// Expand a ligature or special letter that NFD does not decompose (these carry
+/// no combining mark to strip), preserving case for the later lowercase step.
+fn push_folded_char(out: &mut String, c: char) {
+    match c {
+        'æ' => out.push_str("ae"),
+        'Æ' => out.push_str("AE"),
+        'œ' => out.push_str("oe"),
+        'Œ' => out.push_str("OE"),
+        'ß' => out.push_str("ss"),
+        'ø' => out.push('o'),
+        'Ø' => out.push('O'),
+        'ł' => out.push('l'),
+        'Ł' => out.push('L'),
+        _ => out.push(c),
+    }
+}
+
+/// Fold European diacritics and ligatures to ASCII: expand the ligature table,
+/// then NFD-decompose and strip combining marks. "Gödel"→"Godel",
+/// "Œuvre"→"OEuvre", "Straße"→"Strasse" before lowercasing.
+fn fold_unicode(s: &str) -> String {
+    let mut expanded = String::with_capacity(s.len());
+    for c in s.chars() {
+        push_folded_char(&mut expanded, c);
+    }
+    expanded.nfd().filter(|c| !is_combining_mark(*c)).collect()
+}
+
+/// Normalize text but preserve letter case: fold full-width forms, optionally
+/// fold Unicode diacritics and ligatures, and collapse whitespace. The fzf path
+/// needs the original case to compute its case-mismatch penalty, so it uses this
+/// instead of [`normalize`]. `fold` toggles the diacritic/ligature folding step.
+fn normalize_cased(s: &str, fold: bool) -> String {
+    let base = if fold {
+        fold_unicode(s)
+    } else {
+        s.to_string()
+    };
+    base.chars()
+        .map(fold_fullwidth)
+        .collect::<String>()
         .split_whitespace()
         .collect::<Vec<_>>()
         .join(" ")
 }
 
+/// Normalize text: like [`normalize_cased`] but also lowercases, for the
+/// case-insensitive token-sort and Dice paths. `fold` is threaded from the
+/// batch's scoring configuration rather than read from shared mutable state.
+fn normalize(s: &str, fold: bool) -> String {
+    normalize_cased(s, fold).to_lowercase()
+}
+
+/// Does this code point belong to a CJK script (Han, Kana, or Hangul)?
+fn is_cjk_char(c: char) -> bool {
+    matches!(c as u32,
+        0x3400..=0x4DBF |   // CJK Unified Ideographs Extension A
+        0x4E00..=0x9FFF |   // CJK Unified Ideographs
+        0xF900..=0xFAFF |   // CJK Compatibility Ideographs
+        0x3040..=0x309F |   // Hiragana
+        0x30A0..=0x30FF |   // Katakana
+        0xAC00..=0xD7AF     // Hangul syllables
+    )
+}
+
+/// Is a string dominated by CJK script? A title counts as CJK when the
+/// majority of its "content" code points (letters, digits, ideographs) are
+/// in a CJK range — such titles carry no word spaces, so token-sort
+/// Jaro-Winkler collapses them to a single token and is meaningless.
+fn is_cjk_dominant(s: &str) -> bool {
+    let mut cjk = 0usize;
+    let mut total = 0usize;
+    for c in s.chars() {
+        if is_cjk_char(c) {
+            cjk += 1;
+            total += 1;
+        } else if c.is_alphanumeric() {
+            total += 1;
+        }
+    }
+    total > 0 && cjk * 2 > total
+}
+
+/// Character-shingle Dice coefficient over the trigram sets, scaled to 0-100.
+/// Used for CJK titles where whitespace tokenization does not apply; falls
+/// back to exact-equality scoring when either string is shorter than a
+/// trigram (fewer than 3 code points).
+fn trigram_dice_score(s1: &str, s2: &str) -> f64 {
+    if s1.chars().count() < 3 || s2.chars().count() < 3 {
+        return if s1 == s2 { 100.0 } else { 0.0 };
+    }
+    let t1 = extract_trigrams(s1);
+    let t2 = extract_trigrams(s2);
+    let total = t1.len() + t2.len();
+    if total == 0 {
+        return if s1 == s2 { 100.0 } else { 0.0 };
+    }
+    let intersection = t1.intersection(&t2).count();
+    (2.0 * intersection as f64 / total as f64) * 100.0
+}
+
 /// Tokenize and sort tokens alphabetically
 fn tokenize_and_sort(s: &str) -> Vec<&str> {
     let mut tokens: Vec<&str> = s.split_whitespace().collect();
@@ -191,13 +322,13 @@ fn tokenize_and_sort(s: &str) -> Vec<&str> {
 }
 
 /// Internal token sort ratio returning f64 (0.0-100.0)
-fn token_sort_ratio_f64(s1: &str, s2: &str) -> f64 {
+fn token_sort_ratio_f64(s1: &str, s2: &str, fold: bool) -> f64 {
     if s1.is_empty() || s2.is_empty() {
         return 0.0;
     }
 
-    let norm1 = normalize(s1);
-    let norm2 = normalize(s2);
+    let norm1 = normalize(s1, fold);
+    let norm2 = normalize(s2, fold);
 
     token_sort_ratio_f64_prenormalized(&norm1, &norm2)
 }
@@ -218,7 +349,7 @@ fn token_sort_ratio_f64_prenormalized(norm1: &str, norm2: &str) -> f64 {
 /// Token sort ratio for Python: returns float 0.0-100.0
 #[pyfunction]
 fn token_sort_ratio(s1: &str, s2: &str) -> f64 {
-    token_sort_ratio_f64(s1, s2)
+    token_sort_ratio_f64(s1, s2, true)
 }
 
 /// Input data for a single BibItem (for scoring)
@@ -235,6 +366,43 @@ struct BibItemData {
     number: Option<String>,
     pages: Option<String>,
     publisher: Option<String>,
+    /// GB/T 7714 language/script tag (e.g. `zh`, `ja`, `en`, `ru`). When present
+    /// it decides the title path (`zh`/`ja`/`ko` select CJK, any other tag the
+    /// Latin path); when absent the detected Unicode script decides, so untagged
+    /// or mixed corpora still work.
+    #[pyo3(default)]
+    language: Option<String>,
+    /// BibTeX entry type (`article`, `book`, `inproceedings`, ...). Drives the
+    /// per-type weight profile and bonus-field routing in `score_candidate`.
+    #[pyo3(default)]
+    entry_type: Option<String>,
+    /// Editor list, scored through the same author-list path as `author`.
+    #[pyo3(default)]
+    editor: Option<String>,
+    /// Container title for chapters/proceedings papers (`@incollection`,
+    /// `@inproceedings`); fuzzy-matched like a title.
+    #[pyo3(default)]
+    booktitle: Option<String>,
+    #[pyo3(default)]
+    series: Option<String>,
+    #[pyo3(default)]
+    edition: Option<String>,
+    #[pyo3(default)]
+    institution: Option<String>,
+    #[pyo3(default)]
+    organization: Option<String>,
+    /// ISBN (books) — an exact match is a DOI-grade confidence signal.
+    #[pyo3(default)]
+    isbn: Option<String>,
+    /// ISSN (serials) — an exact match is a DOI-grade confidence signal.
+    #[pyo3(default)]
+    issn: Option<String>,
+    #[pyo3(default)]
+    address: Option<String>,
+    #[pyo3(default)]
+    url: Option<String>,
+    #[pyo3(default)]
+    urldate: Option<String>,
 }
 
 /// Result of scoring a candidate against a subject
@@ -246,6 +414,13 @@ struct MatchResult {
     author_score: f64,
     date_score: f64,
     bonus_score: f64,
+    /// Exact-word / exact-phrase title signal (weighted).
+    exactness_score: f64,
+    /// Word-proximity title signal (weighted).
+    proximity_score: f64,
+    /// Per-rule bucket keys (higher is better) when the ranking-rule strategy
+    /// is active, for explainability; `None` under the weighted-sum strategy.
+    ranking_bucket: Option<Vec<i64>>,
 }
 
 impl PartialEq for MatchResult {
@@ -270,15 +445,277 @@ impl Ord for MatchResult {
     }
 }
 
+/// A single rule in the ordered ranking pipeline. Each rule maps a scored
+/// candidate to a discrete bucket (higher is better); candidates are sorted by
+/// the first rule, ties are broken by the next, and so on — a lexicographic
+/// bucket sort rather than one weighted sum. Mirrors Meilisearch's ranking
+/// rules, so "DOI equality always wins, then title, then author, then date" is
+/// expressible as `[ExactDoi, TitleTypo, Author, Date]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RankingRule {
+    ExactDoi,
+    TitleTypo,
+    Author,
+    Proximity,
+    Date,
+}
+
+/// Parse a ranking-rule name from config; unknown names return `None` so the
+/// caller can drop them and degrade gracefully rather than panicking.
+fn ranking_rule_from(name: &str) -> Option<RankingRule> {
+    match name.trim().to_lowercase().as_str() {
+        "exactdoi" | "exact_doi" | "doi" => Some(RankingRule::ExactDoi),
+        "titletypo" | "title_typo" | "title" => Some(RankingRule::TitleTypo),
+        "author" => Some(RankingRule::Author),
+        "proximity" => Some(RankingRule::Proximity),
+        "date" => Some(RankingRule::Date),
+        _ => None,
+    }
+}
+
+/// Resolve an ordered list of rule names into rules, dropping unknown entries.
+fn ranking_rules_from(names: &[String]) -> Vec<RankingRule> {
+    names.iter().filter_map(|n| ranking_rule_from(n)).collect()
+}
+
+/// Bucket key (higher is better) for one rule applied to a scored candidate.
+/// Component scores are discretized into coarse buckets so near-equal scores
+/// tie and fall through to the next rule — the lexicographic behavior.
+fn rule_bucket(
+    rule: RankingRule,
+    subject: &BibItemData,
+    candidate: &BibItemData,
+    result: &MatchResult,
+) -> i64 {
+    match rule {
+        RankingRule::ExactDoi => match (&subject.doi, &candidate.doi) {
+            (Some(a), Some(b)) if !a.is_empty() && a == b => 1,
+            _ => 0,
+        },
+        RankingRule::TitleTypo => (result.title_score / 5.0) as i64,
+        RankingRule::Author => (result.author_score / 5.0) as i64,
+        RankingRule::Proximity => (result.proximity_score / 5.0) as i64,
+        RankingRule::Date => (result.date_score / 5.0) as i64,
+    }
+}
+
+/// Bucket vector across the full rule pipeline, in rule order.
+fn ranking_buckets(
+    rules: &[RankingRule],
+    subject: &BibItemData,
+    candidate: &BibItemData,
+    result: &MatchResult,
+) -> Vec<i64> {
+    rules
+        .iter()
+        .map(|&r| rule_bucket(r, subject, candidate, result))
+        .collect()
+}
+
+/// Decide whether a title should be scored on the CJK path. An explicit
+/// GB/T 7714 language tag (`zh`, `ja`, `ko`) wins over detection; otherwise the
+/// dominant Unicode script of the text decides, so untagged corpora still work.
+fn uses_cjk_path(text: &str, language: Option<&str>) -> bool {
+    match language {
+        Some(lang) => {
+            let lang = lang.to_lowercase();
+            lang.starts_with("zh") || lang.starts_with("ja") || lang.starts_with("ko")
+        }
+        None => is_cjk_dominant(text),
+    }
+}
+
+// === fzf/Smith-Waterman positional title scorer ===
+
+const FZF_MATCH: f64 = 16.0;
+const FZF_BOUNDARY: f64 = 8.0;
+const FZF_CONSECUTIVE: f64 = 4.0;
+const FZF_GAP_START: f64 = 3.0;
+const FZF_GAP_EXTENSION: f64 = 1.0;
+const FZF_CASE_PENALTY: f64 = 2.0;
+
+/// Selectable title-similarity algorithm. `TokenSort` is the historical
+/// sorted-token Jaro-Winkler; `Fzf` is the positional alignment scorer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TitleAlgorithm {
+    TokenSort,
+    Fzf,
+}
+
+/// Resolve the algorithm name coming from the `Weights` config. Both `"fzf"`
+/// and `"fzf_ratio"` select the positional scorer.
+fn title_algorithm_from(name: Option<&str>) -> TitleAlgorithm {
+    match name {
+        Some(n) if n.eq_ignore_ascii_case("fzf") || n.eq_ignore_ascii_case("fzf_ratio") => {
+            TitleAlgorithm::Fzf
+        }
+        _ => TitleAlgorithm::TokenSort,
+    }
+}
+
+/// Cheap ordered-subsequence prefilter: does every pattern byte occur in the
+/// text in order? A `memchr` forward scan rejects hopeless pairs before the
+/// quadratic DP runs. Operates on lowercased UTF-8 bytes — a char subsequence
+/// is always a byte subsequence, so this never rejects a genuine match.
+fn fzf_prefilter(pattern_low: &[u8], text_low: &[u8]) -> bool {
+    let mut cursor = 0;
+    for &b in pattern_low {
+        match memchr::memchr(b, &text_low[cursor..]) {
+            Some(pos) => cursor += pos + 1,
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Is the text position at `cur` a word boundary? True at the string start, or
+/// after a non-alphanumeric char, or on a lower→upper case transition.
+fn is_boundary(prev: Option<char>, cur: char) -> bool {
+    match prev {
+        None => true,
+        Some(p) => !p.is_alphanumeric() || (p.is_lowercase() && cur.is_uppercase()),
+    }
+}
+
+/// Raw Smith-Waterman-style alignment score of `pattern` against `text`.
+/// Returns 0.0 when not every pattern char can be aligned in order.
+fn fzf_raw(pattern: &[char], p_low: &[char], text: &[char]) -> f64 {
+    let (pl, tl) = (pattern.len(), text.len());
+    if pl == 0 || tl == 0 || pl > tl {
+        return 0.0;
+    }
+    let t_low: Vec<char> = text
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    // Prefilter: bail out before the DP unless the pattern is an ordered
+    // subsequence of the text.
+    let p_low_bytes: String = p_low.iter().collect();
+    let t_low_bytes: String = t_low.iter().collect();
+    if !fzf_prefilter(p_low_bytes.as_bytes(), t_low_bytes.as_bytes()) {
+        return 0.0;
+    }
+
+    let neg = f64::NEG_INFINITY;
+    let mut h = vec![vec![neg; tl]; pl];
+    let mut streak = vec![vec![0u32; tl]; pl];
+
+    for i in 0..pl {
+        for j in 0..tl {
+            if p_low[i] != t_low[j] {
+                continue;
+            }
+            let prev_text = if j > 0 { Some(text[j - 1]) } else { None };
+            let boundary = if is_boundary(prev_text, text[j]) {
+                FZF_BOUNDARY
+            } else {
+                0.0
+            };
+            // Case-mismatch penalty: source uppercase matched against lowercase.
+            let case_pen = if pattern[i].is_uppercase() && text[j].is_lowercase() {
+                FZF_CASE_PENALTY
+            } else {
+                0.0
+            };
+            let base = FZF_MATCH + boundary - case_pen;
+
+            if i == 0 {
+                h[i][j] = base;
+                streak[i][j] = 1;
+                continue;
+            }
+            let mut best = neg;
+            let mut best_streak = 1;
+            for k in 0..j {
+                if h[i - 1][k] == neg {
+                    continue;
+                }
+                let gap = j - 1 - k;
+                let (cand, st) = if gap == 0 {
+                    // Consecutive match: streak bonus grows with the run length.
+                    (
+                        h[i - 1][k] + base + FZF_CONSECUTIVE * streak[i - 1][k] as f64,
+                        streak[i - 1][k] + 1,
+                    )
+                } else {
+                    let pen = FZF_GAP_START + (gap as f64 - 1.0) * FZF_GAP_EXTENSION;
+                    (h[i - 1][k] + base - pen, 1)
+                };
+                if cand > best {
+                    best = cand;
+                    best_streak = st;
+                }
+            }
+            if best > neg {
+                h[i][j] = best;
+                streak[i][j] = best_streak;
+            }
+        }
+    }
+
+    h[pl - 1].iter().copied().fold(neg, f64::max).max(0.0)
+}
+
+/// fzf positional similarity scaled to 0-100, normalized by the best score the
+/// subject could achieve against itself so identical strings score 100.
+fn fzf_score(subject: &str, candidate: &str) -> f64 {
+    let pattern: Vec<char> = subject.chars().filter(|c| !c.is_whitespace()).collect();
+    if pattern.is_empty() {
+        return 0.0;
+    }
+    let p_low: Vec<char> = pattern
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let text: Vec<char> = candidate.chars().collect();
+
+    let raw = fzf_raw(&pattern, &p_low, &text);
+    if raw <= 0.0 {
+        return 0.0;
+    }
+    let own: Vec<char> = subject.chars().collect();
+    let best = fzf_raw(&pattern, &p_low, &own);
+    if best <= 0.0 {
+        return 0.0;
+    }
+    (raw / best * 100.0).min(100.0)
+}
+
 /// Score title similarity with bonuses (takes pre-normalized title for subject)
-fn score_title_prenorm(norm_subject: &str, title2: &str, weight: f64) -> f64 {
+fn score_title_prenorm(
+    norm_subject: &str,
+    norm_subject_cased: &str,
+    title2: &str,
+    subj_lang: Option<&str>,
+    cand_lang: Option<&str>,
+    algorithm: TitleAlgorithm,
+    weight: f64,
+    fold: bool,
+) -> f64 {
     if norm_subject.is_empty() || title2.is_empty() {
         return 0.0;
     }
 
-    let norm2 = normalize(title2);
+    let norm2 = normalize(title2, fold);
 
-    let raw_score = token_sort_ratio_f64_prenormalized(norm_subject, &norm2);
+    // Script-aware path selection: space-delimited token-sort Jaro-Winkler is
+    // meaningless for CJK titles (no word spaces), so both-CJK pairs score with
+    // a character-shingle Dice coefficient instead. Mixed corpora need no config.
+    let raw_score = if uses_cjk_path(norm_subject, subj_lang) && uses_cjk_path(&norm2, cand_lang) {
+        trigram_dice_score(norm_subject, &norm2)
+    } else {
+        match algorithm {
+            TitleAlgorithm::TokenSort => {
+                token_sort_ratio_f64_prenormalized(norm_subject, &norm2)
+            }
+            // fzf compares case-preserving forms so its boundary and
+            // case-mismatch signals stay live; matching is case-insensitive.
+            TitleAlgorithm::Fzf => {
+                fzf_score(norm_subject_cased, &normalize_cased(title2, fold))
+            }
+        }
+    };
 
     // Check if one title contains the other (subtitle handling)
     let one_contains_other = norm_subject.contains(&norm2) || norm2.contains(norm_subject);
@@ -308,13 +745,31 @@ fn score_title_prenorm(norm_subject: &str, title2: &str, weight: f64) -> f64 {
 }
 
 /// Score title similarity with bonuses (normalizes both titles)
-fn score_title(title1: &str, title2: &str, weight: f64) -> f64 {
+fn score_title(
+    title1: &str,
+    title2: &str,
+    subj_lang: Option<&str>,
+    cand_lang: Option<&str>,
+    algorithm: TitleAlgorithm,
+    weight: f64,
+    fold: bool,
+) -> f64 {
     if title1.is_empty() || title2.is_empty() {
         return 0.0;
     }
 
-    let norm1 = normalize(title1);
-    score_title_prenorm(&norm1, title2, weight)
+    let norm1_cased = normalize_cased(title1, fold);
+    let norm1 = norm1_cased.to_lowercase();
+    score_title_prenorm(
+        &norm1,
+        &norm1_cased,
+        title2,
+        subj_lang,
+        cand_lang,
+        algorithm,
+        weight,
+        fold,
+    )
 }
 
 /// Check if a name part is an initial (e.g., "E." or "E")
@@ -346,7 +801,7 @@ fn extract_name_parts(author: &str) -> (Vec<&str>, &str) {
 
 /// Check if one author string uses initials that match the other's full names.
 /// Handles cases like "E. M. Adams" vs "Ernest M. Adams" or "J. Smith" vs "John Smith".
-fn check_initials_match(author1: &str, author2: &str) -> bool {
+fn check_initials_match(author1: &str, author2: &str, fold: bool) -> bool {
     let (given1, surname1) = extract_name_parts(author1);
     let (given2, surname2) = extract_name_parts(author2);
 
@@ -358,7 +813,8 @@ fn check_initials_match(author1: &str, author2: &str) -> bool {
     }
 
     // Fuzzy surname check (only call once, not in loop)
-    let surname_score = token_sort_ratio_f64(&surname1.to_lowercase(), &surname2.to_lowercase());
+    let surname_score =
+        token_sort_ratio_f64(&surname1.to_lowercase(), &surname2.to_lowercase(), fold);
     if surname_score < 80.0 {
         return false;
     }
@@ -399,25 +855,201 @@ fn check_initials_match(author1: &str, author2: &str) -> bool {
     matches > 0 && matches >= min_names.saturating_sub(1)
 }
 
-/// Score author similarity with bonuses
-fn score_author(author1: &str, author2: &str, weight: f64) -> f64 {
+/// Lowercase von-particles that belong to the surname in the BibTeX name model.
+const VON_PARTICLES: &[&str] = &[
+    "de", "van", "von", "del", "della", "la", "le", "di", "der", "den", "du",
+];
+
+/// Name suffixes (the BibTeX "Jr" slot) stripped before comparison.
+const NAME_SUFFIXES: &[&str] = &["jr", "sr", "ii", "iii"];
+
+/// A single parsed person in the BibTeX {First, von, Last, Jr} model.
+/// We keep the given names and the (possibly multi-word) surname; the suffix
+/// is dropped as it carries no matching signal.
+struct ParsedName {
+    given: Vec<String>,
+    surname: String,
+}
+
+impl ParsedName {
+    /// Reconstruct a "given... surname" string for the whole-name comparators.
+    fn canonical(&self) -> String {
+        if self.given.is_empty() {
+            self.surname.clone()
+        } else {
+            format!("{} {}", self.given.join(" "), self.surname)
+        }
+    }
+}
+
+fn is_suffix_token(token: &str) -> bool {
+    NAME_SUFFIXES.contains(&token.trim_end_matches('.').to_lowercase().as_str())
+}
+
+fn is_von_token(token: &str) -> bool {
+    VON_PARTICLES.contains(&token.to_lowercase().as_str())
+}
+
+/// Parse one author into (given-names, von+surname), handling the "Last, First"
+/// comma form, lowercase von particles, and trailing suffixes.
+fn parse_name(name: &str) -> ParsedName {
+    let name = name.trim();
+    if name.contains(',') {
+        // "von Last, First" or "von Last, Jr, First"
+        let segments: Vec<&str> = name.split(',').map(str::trim).collect();
+        let surname = segments[0].to_string();
+        let given = segments
+            .last()
+            .map(|g| g.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default();
+        return ParsedName { given, surname };
+    }
+
+    // "First [von] Last [Suffix]"
+    let mut tokens: Vec<&str> = name.split_whitespace().collect();
+    while tokens.last().is_some_and(|t| is_suffix_token(t)) {
+        tokens.pop();
+    }
+    if tokens.is_empty() {
+        return ParsedName {
+            given: vec![],
+            surname: String::new(),
+        };
+    }
+    match tokens.iter().position(|t| is_von_token(t)) {
+        Some(idx) if idx > 0 => ParsedName {
+            given: tokens[..idx].iter().map(|t| t.to_string()).collect(),
+            surname: tokens[idx..].join(" "),
+        },
+        _ => {
+            let surname = tokens.last().unwrap().to_string();
+            ParsedName {
+                given: tokens[..tokens.len() - 1]
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect(),
+                surname,
+            }
+        }
+    }
+}
+
+/// Strip a trailing case-insensitive "et al." / "et al" from `person`, returning
+/// the head slice. Operates on the original string (the `et al.` suffix is ASCII,
+/// so the cut is always on a char boundary) to avoid panicking on inputs whose
+/// lowercasing changes byte length (e.g. Turkish `İ`).
+fn strip_et_al_suffix(person: &str) -> Option<&str> {
+    let trimmed = person.trim_end();
+    for suffix in ["et al.", "et al"] {
+        if trimmed.len() >= suffix.len() {
+            let idx = trimmed.len() - suffix.len();
+            if trimmed.is_char_boundary(idx) {
+                let (head, tail) = trimmed.split_at(idx);
+                if tail.eq_ignore_ascii_case(suffix) {
+                    return Some(head);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Split an author field into individual people, recognizing " and ", ";" and
+/// "&" separators. Returns the people plus whether a trailing "et al." (or
+/// "others") was present, which marks the list as truncated.
+fn split_author_list(field: &str) -> (Vec<String>, bool) {
+    let unified = field.replace(';', " and ").replace('&', " and ");
+    let mut people = Vec::new();
+    let mut et_al = false;
+    for raw in unified.split(" and ") {
+        let mut person = raw.trim();
+        let low = person.to_lowercase();
+        if low == "et al" || low == "et al." || low == "others" {
+            et_al = true;
+            continue;
+        }
+        if let Some(head) = strip_et_al_suffix(person) {
+            et_al = true;
+            person = head.trim();
+        }
+        if !person.is_empty() {
+            people.push(person.to_string());
+        }
+    }
+    (people, et_al)
+}
+
+/// Score a single pair of people, mirroring the whole-string author bonuses:
+/// a strong fuzzy match earns +100, an initials-only match earns +50.
+fn score_person_pair(a: &str, b: &str, fold: bool) -> f64 {
+    let raw = token_sort_ratio_f64(a, b, fold);
+    if raw > 85.0 {
+        raw + 100.0
+    } else if check_initials_match(a, b, fold) {
+        raw + 50.0
+    } else {
+        raw
+    }
+}
+
+/// Score author similarity with bonuses.
+///
+/// Each side is parsed into a list of people; the lists are matched greedily
+/// (best unused pairing per subject person), the matched scores are averaged,
+/// and a penalty proportional to the unmatched people is applied — unless
+/// either side ends in "et al.", which suppresses the penalty since the list
+/// is known to be truncated.
+fn score_author(author1: &str, author2: &str, weight: f64, fold: bool) -> f64 {
     if author1.is_empty() || author2.is_empty() {
         return 0.0;
     }
 
-    let raw_score = token_sort_ratio_f64(author1, author2);
-    let mut final_score = raw_score;
+    let (people1, et_al1) = split_author_list(author1);
+    let (people2, et_al2) = split_author_list(author2);
+    if people1.is_empty() || people2.is_empty() {
+        return 0.0;
+    }
 
-    if raw_score > 85.0 {
-        final_score += 100.0;
-    } else {
-        // Check for initial matching (e.g., "E. M. Adams" vs "Ernest M. Adams")
-        if check_initials_match(author1, author2) {
-            final_score += 50.0;
+    let canon1: Vec<String> = people1.iter().map(|p| parse_name(p).canonical()).collect();
+    let canon2: Vec<String> = people2.iter().map(|p| parse_name(p).canonical()).collect();
+
+    // Greedy bipartite match: each subject person claims its best unused candidate.
+    let mut used = vec![false; canon2.len()];
+    let mut matched_sum = 0.0;
+    let mut matched = 0usize;
+    for a in &canon1 {
+        let mut best = f64::NEG_INFINITY;
+        let mut best_j = None;
+        for (j, b) in canon2.iter().enumerate() {
+            if used[j] {
+                continue;
+            }
+            let s = score_person_pair(a, b, fold);
+            if s > best {
+                best = s;
+                best_j = Some(j);
+            }
+        }
+        if let Some(j) = best_j {
+            used[j] = true;
+            matched_sum += best;
+            matched += 1;
         }
     }
 
-    final_score * weight
+    if matched == 0 {
+        return 0.0;
+    }
+
+    let avg = matched_sum / matched as f64;
+    let unmatched = canon1.len().max(canon2.len()) - matched;
+    let penalty = if et_al1 || et_al2 {
+        0.0
+    } else {
+        unmatched as f64 * 50.0
+    };
+
+    (avg - penalty).max(0.0) * weight
 }
 
 /// Score date similarity with wider tolerance for CrossRef date discrepancies
@@ -442,115 +1074,324 @@ fn score_date(year1: Option<i32>, year2: Option<i32>, weight: f64) -> f64 {
     }
 }
 
-/// Score bonus fields (DOI, journal+vol+num, pages, publisher)
-fn score_bonus(subject: &BibItemData, candidate: &BibItemData, weight: f64) -> f64 {
+/// Score bonus fields (DOI, journal+vol+num, pages, publisher, ISBN/ISSN,
+/// editor, booktitle, series, institution, address, edition, URL), gated by the
+/// entry-type's enabled field set.
+fn score_bonus(
+    subject: &BibItemData,
+    candidate: &BibItemData,
+    fields: &BonusFieldSet,
+    weight: f64,
+    fold: bool,
+) -> f64 {
     let mut bonus = 0.0;
 
     // DOI exact match (highest confidence)
-    if let (Some(ref doi1), Some(ref doi2)) = (&subject.doi, &candidate.doi) {
-        if !doi1.is_empty() && doi1 == doi2 {
-            bonus += 100.0;
+    if fields.doi {
+        if let (Some(ref doi1), Some(ref doi2)) = (&subject.doi, &candidate.doi) {
+            if !doi1.is_empty() && doi1 == doi2 {
+                bonus += 100.0;
+            }
         }
     }
 
     // Journal + Volume + Number match
-    if let (Some(ref j1), Some(ref j2)) = (&subject.journal, &candidate.journal) {
-        let norm_j1 = normalize(j1);
-        let norm_j2 = normalize(j2);
-        if !norm_j1.is_empty() && norm_j1 == norm_j2 {
-            let vol_match = match (&subject.volume, &candidate.volume) {
-                (Some(v1), Some(v2)) => !v1.is_empty() && v1 == v2,
-                _ => false,
-            };
-            let num_match = match (&subject.number, &candidate.number) {
-                (Some(n1), Some(n2)) => !n1.is_empty() && n1 == n2,
-                _ => false,
-            };
-            if vol_match && num_match {
-                bonus += 50.0;
+    if fields.journal {
+        if let (Some(ref j1), Some(ref j2)) = (&subject.journal, &candidate.journal) {
+            let norm_j1 = normalize(j1, fold);
+            let norm_j2 = normalize(j2, fold);
+            if !norm_j1.is_empty() && norm_j1 == norm_j2 {
+                let vol_match = match (&subject.volume, &candidate.volume) {
+                    (Some(v1), Some(v2)) => !v1.is_empty() && v1 == v2,
+                    _ => false,
+                };
+                let num_match = match (&subject.number, &candidate.number) {
+                    (Some(n1), Some(n2)) => !n1.is_empty() && n1 == n2,
+                    _ => false,
+                };
+                if vol_match && num_match {
+                    bonus += 50.0;
+                }
             }
         }
     }
 
     // Pages match
-    if let (Some(ref p1), Some(ref p2)) = (&subject.pages, &candidate.pages) {
-        if !p1.is_empty() && p1 == p2 {
-            bonus += 20.0;
+    if fields.pages {
+        if let (Some(ref p1), Some(ref p2)) = (&subject.pages, &candidate.pages) {
+            if !p1.is_empty() && p1 == p2 {
+                bonus += 20.0;
+            }
         }
     }
 
     // Publisher match
-    if let (Some(ref pub1), Some(ref pub2)) = (&subject.publisher, &candidate.publisher) {
-        if !pub1.is_empty() && !pub2.is_empty() {
-            let pub_score = token_sort_ratio_f64(pub1, pub2);
-            if pub_score > 85.0 {
-                bonus += 10.0;
+    if fields.publisher {
+        if let (Some(ref pub1), Some(ref pub2)) = (&subject.publisher, &candidate.publisher) {
+            if !pub1.is_empty() && !pub2.is_empty() {
+                let pub_score = token_sort_ratio_f64(pub1, pub2, fold);
+                if pub_score > 85.0 {
+                    bonus += 10.0;
+                }
             }
         }
     }
 
+    // ISBN / ISSN exact match (DOI-grade confidence)
+    if fields.isbn_issn {
+        bonus += isbn_issn_bonus(&subject.isbn, &candidate.isbn);
+        bonus += isbn_issn_bonus(&subject.issn, &candidate.issn);
+    }
+
+    // Editor list, scored through the author-list path
+    if fields.editor {
+        if let (Some(ref e1), Some(ref e2)) = (&subject.editor, &candidate.editor) {
+            if score_author(e1, e2, 1.0, fold) > 80.0 {
+                bonus += 15.0;
+            }
+        }
+    }
+
+    // Container title for chapters/proceedings papers
+    if fields.booktitle && fuzzy_match(&subject.booktitle, &candidate.booktitle, 85.0, fold) {
+        bonus += 15.0;
+    }
+
+    // Series / collection name
+    if fields.series && fuzzy_match(&subject.series, &candidate.series, 85.0, fold) {
+        bonus += 5.0;
+    }
+
+    // Institution / organization (theses, technical reports)
+    let subj_inst = subject.institution.as_ref().or(subject.organization.as_ref());
+    let cand_inst = candidate
+        .institution
+        .as_ref()
+        .or(candidate.organization.as_ref());
+    if fields.institution && fuzzy_pair(subj_inst, cand_inst, 85.0, fold) {
+        bonus += 10.0;
+    }
+
+    // Publisher address / place of publication
+    if fields.address && fuzzy_match(&subject.address, &candidate.address, 85.0, fold) {
+        bonus += 5.0;
+    }
+
+    // Edition statement, exact after normalization
+    if fields.edition && exact_normalized(&subject.edition, &candidate.edition, fold) {
+        bonus += 5.0;
+    }
+
+    // Canonical URL (urldate is not a matching signal)
+    if fields.url && exact_trimmed(&subject.url, &candidate.url) {
+        bonus += 10.0;
+    }
+
     bonus * weight
 }
 
+/// Exact-match bonus (100 points) for two optional ISBN/ISSN identifiers.
+fn isbn_issn_bonus(a: &Option<String>, b: &Option<String>) -> f64 {
+    match (a, b) {
+        (Some(x), Some(y)) if !x.is_empty() && x == y => 100.0,
+        _ => 0.0,
+    }
+}
+
+/// True when both identifiers are present and equal after trimming whitespace.
+fn exact_trimmed(a: &Option<String>, b: &Option<String>) -> bool {
+    match (a, b) {
+        (Some(x), Some(y)) => !x.trim().is_empty() && x.trim() == y.trim(),
+        _ => false,
+    }
+}
+
+/// True when both fields normalize to the same non-empty string.
+fn exact_normalized(a: &Option<String>, b: &Option<String>, fold: bool) -> bool {
+    match (a, b) {
+        (Some(x), Some(y)) => {
+            let nx = normalize(x, fold);
+            !nx.is_empty() && nx == normalize(y, fold)
+        }
+        _ => false,
+    }
+}
+
+/// True when both optional string fields fuzzy-match above `threshold`.
+fn fuzzy_match(a: &Option<String>, b: &Option<String>, threshold: f64, fold: bool) -> bool {
+    match (a, b) {
+        (Some(x), Some(y)) if !x.is_empty() && !y.is_empty() => {
+            token_sort_ratio_f64(x, y, fold) > threshold
+        }
+        _ => false,
+    }
+}
+
+/// Like [`fuzzy_match`] but over already-resolved `Option<&String>` references.
+fn fuzzy_pair(a: Option<&String>, b: Option<&String>, threshold: f64, fold: bool) -> bool {
+    match (a, b) {
+        (Some(x), Some(y)) if !x.is_empty() && !y.is_empty() => {
+            token_sort_ratio_f64(x, y, fold) > threshold
+        }
+        _ => false,
+    }
+}
+
 /// Score bonus fields with precomputed subject data (avoids repeated normalization)
 fn score_bonus_precomputed(
     subject: &PrecomputedSubject,
     candidate: &BibItemData,
+    fields: &BonusFieldSet,
     weight: f64,
+    fold: bool,
 ) -> f64 {
     let mut bonus = 0.0;
 
     // DOI exact match (highest confidence)
-    if let (Some(ref doi1), Some(ref doi2)) = (&subject.data.doi, &candidate.doi) {
-        if !doi1.is_empty() && doi1 == doi2 {
-            bonus += 100.0;
+    if fields.doi {
+        if let (Some(ref doi1), Some(ref doi2)) = (&subject.data.doi, &candidate.doi) {
+            if !doi1.is_empty() && doi1 == doi2 {
+                bonus += 100.0;
+            }
         }
     }
 
     // Journal + Volume + Number match (use precomputed normalized journal)
-    if let (Some(ref norm_j1), Some(ref j2)) = (&subject.normalized_journal, &candidate.journal) {
-        let norm_j2 = normalize(j2);
-        if !norm_j1.is_empty() && norm_j1 == &norm_j2 {
-            let vol_match = match (&subject.data.volume, &candidate.volume) {
-                (Some(v1), Some(v2)) => !v1.is_empty() && v1 == v2,
-                _ => false,
-            };
-            let num_match = match (&subject.data.number, &candidate.number) {
-                (Some(n1), Some(n2)) => !n1.is_empty() && n1 == n2,
-                _ => false,
-            };
-            if vol_match && num_match {
-                bonus += 50.0;
+    if fields.journal {
+        if let (Some(ref norm_j1), Some(ref j2)) =
+            (&subject.normalized_journal, &candidate.journal)
+        {
+            let norm_j2 = normalize(j2, fold);
+            if !norm_j1.is_empty() && norm_j1 == &norm_j2 {
+                let vol_match = match (&subject.data.volume, &candidate.volume) {
+                    (Some(v1), Some(v2)) => !v1.is_empty() && v1 == v2,
+                    _ => false,
+                };
+                let num_match = match (&subject.data.number, &candidate.number) {
+                    (Some(n1), Some(n2)) => !n1.is_empty() && n1 == n2,
+                    _ => false,
+                };
+                if vol_match && num_match {
+                    bonus += 50.0;
+                }
             }
         }
     }
 
     // Pages match
-    if let (Some(ref p1), Some(ref p2)) = (&subject.data.pages, &candidate.pages) {
-        if !p1.is_empty() && p1 == p2 {
-            bonus += 20.0;
+    if fields.pages {
+        if let (Some(ref p1), Some(ref p2)) = (&subject.data.pages, &candidate.pages) {
+            if !p1.is_empty() && p1 == p2 {
+                bonus += 20.0;
+            }
         }
     }
 
     // Publisher match (use precomputed normalized publisher)
-    if let (Some(ref norm_pub1), Some(ref pub2)) =
-        (&subject.normalized_publisher, &candidate.publisher)
-    {
-        if !norm_pub1.is_empty() && !pub2.is_empty() {
-            let norm_pub2 = normalize(pub2);
-            let pub_score = token_sort_ratio_f64_prenormalized(norm_pub1, &norm_pub2);
-            if pub_score > 85.0 {
-                bonus += 10.0;
+    if fields.publisher {
+        if let (Some(ref norm_pub1), Some(ref pub2)) =
+            (&subject.normalized_publisher, &candidate.publisher)
+        {
+            if !norm_pub1.is_empty() && !pub2.is_empty() {
+                let norm_pub2 = normalize(pub2, fold);
+                let pub_score = token_sort_ratio_f64_prenormalized(norm_pub1, &norm_pub2);
+                if pub_score > 85.0 {
+                    bonus += 10.0;
+                }
+            }
+        }
+    }
+
+    // ISBN / ISSN exact match (DOI-grade confidence)
+    if fields.isbn_issn {
+        bonus += isbn_issn_bonus(&subject.data.isbn, &candidate.isbn);
+        bonus += isbn_issn_bonus(&subject.data.issn, &candidate.issn);
+    }
+
+    // Editor list, scored through the author-list path
+    if fields.editor {
+        if let (Some(ref e1), Some(ref e2)) = (&subject.data.editor, &candidate.editor) {
+            if score_author(e1, e2, 1.0, fold) > 80.0 {
+                bonus += 15.0;
             }
         }
     }
 
+    // Container title (use precomputed normalized booktitle)
+    if fields.booktitle
+        && fuzzy_prenorm(&subject.normalized_booktitle, &candidate.booktitle, 85.0, fold)
+    {
+        bonus += 15.0;
+    }
+
+    // Series / collection name (precomputed)
+    if fields.series && fuzzy_prenorm(&subject.normalized_series, &candidate.series, 85.0, fold) {
+        bonus += 5.0;
+    }
+
+    // Institution / organization (precomputed)
+    let cand_inst = candidate
+        .institution
+        .as_ref()
+        .or(candidate.organization.as_ref());
+    if fields.institution
+        && fuzzy_prenorm_opt(&subject.normalized_institution, cand_inst, 85.0, fold)
+    {
+        bonus += 10.0;
+    }
+
+    // Publisher address / place of publication (precomputed)
+    if fields.address && fuzzy_prenorm(&subject.normalized_address, &candidate.address, 85.0, fold)
+    {
+        bonus += 5.0;
+    }
+
+    // Edition statement, exact after normalization
+    if fields.edition && exact_normalized(&subject.data.edition, &candidate.edition, fold) {
+        bonus += 5.0;
+    }
+
+    // Canonical URL (urldate is not a matching signal)
+    if fields.url && exact_trimmed(&subject.data.url, &candidate.url) {
+        bonus += 10.0;
+    }
+
     bonus * weight
 }
 
+/// Fuzzy-match a precomputed normalized subject field against a raw candidate.
+fn fuzzy_prenorm(
+    norm_subj: &Option<String>,
+    cand: &Option<String>,
+    threshold: f64,
+    fold: bool,
+) -> bool {
+    match (norm_subj, cand) {
+        (Some(ns), Some(c)) if !ns.is_empty() && !c.is_empty() => {
+            token_sort_ratio_f64_prenormalized(ns, &normalize(c, fold)) > threshold
+        }
+        _ => false,
+    }
+}
+
+/// Like [`fuzzy_prenorm`] but over an already-resolved `Option<&String>` candidate.
+fn fuzzy_prenorm_opt(
+    norm_subj: &Option<String>,
+    cand: Option<&String>,
+    threshold: f64,
+    fold: bool,
+) -> bool {
+    match (norm_subj, cand) {
+        (Some(ns), Some(c)) if !ns.is_empty() && !c.is_empty() => {
+            token_sort_ratio_f64_prenormalized(ns, &normalize(c, fold)) > threshold
+        }
+        _ => false,
+    }
+}
+
 /// Scoring weights for the four matching components.
 /// Mirrors the Python FuzzyMatchWeights TypedDict — passed as a dict from Python.
-#[derive(Clone, Copy, Debug, FromPyObject)]
+#[derive(Clone, Debug, FromPyObject)]
 struct Weights {
     #[pyo3(item)]
     title: f64,
@@ -560,6 +1401,253 @@ struct Weights {
     date: f64,
     #[pyo3(item)]
     bonus: f64,
+    /// Optional title algorithm selector: `"fzf"` or `"fzf_ratio"` for the
+    /// positional scorer, anything else (or absent) keeps the sorted-token
+    /// Jaro-Winkler default.
+    #[pyo3(item, default)]
+    title_algorithm: Option<String>,
+    /// Weight for the exact-word / exact-phrase title signal. Defaults to 0.0,
+    /// so the component is inert unless a caller opts in.
+    #[pyo3(item, default)]
+    exactness: f64,
+    /// Weight for the word-proximity title signal (tighter spans score higher).
+    /// Defaults to 0.0.
+    #[pyo3(item, default)]
+    proximity: f64,
+}
+
+/// Which bonus fields contribute for a given entry type. A `@book` has no
+/// journal/volume/number, while a `@article` has no publisher signal worth
+/// much — routing the fields keeps irrelevant matches from inflating scores.
+#[derive(Clone, Copy, Debug)]
+struct BonusFieldSet {
+    doi: bool,
+    journal: bool,
+    pages: bool,
+    publisher: bool,
+    editor: bool,
+    booktitle: bool,
+    series: bool,
+    edition: bool,
+    institution: bool,
+    /// Combined flag for both `isbn` and `issn` exact-match signals.
+    isbn_issn: bool,
+    address: bool,
+    url: bool,
+}
+
+impl Default for BonusFieldSet {
+    /// All fields enabled — the historical, type-agnostic behavior.
+    fn default() -> Self {
+        Self {
+            doi: true,
+            journal: true,
+            pages: true,
+            publisher: true,
+            editor: true,
+            booktitle: true,
+            series: true,
+            edition: true,
+            institution: true,
+            isbn_issn: true,
+            address: true,
+            url: true,
+        }
+    }
+}
+
+impl BonusFieldSet {
+    /// A set with every field disabled, to be switched on selectively.
+    fn none() -> Self {
+        Self {
+            doi: false,
+            journal: false,
+            pages: false,
+            publisher: false,
+            editor: false,
+            booktitle: false,
+            series: false,
+            edition: false,
+            institution: false,
+            isbn_issn: false,
+            address: false,
+            url: false,
+        }
+    }
+
+    /// Build a set from an explicit list of enabled field names.
+    fn from_names(names: &[String]) -> Self {
+        let mut set = Self::none();
+        for name in names {
+            match name.to_lowercase().as_str() {
+                "doi" => set.doi = true,
+                "journal" => set.journal = true,
+                "pages" => set.pages = true,
+                "publisher" => set.publisher = true,
+                "editor" => set.editor = true,
+                "booktitle" => set.booktitle = true,
+                "series" => set.series = true,
+                "edition" => set.edition = true,
+                "institution" | "organization" => set.institution = true,
+                "isbn" | "issn" => set.isbn_issn = true,
+                "address" => set.address = true,
+                "url" | "urldate" => set.url = true,
+                _ => {}
+            }
+        }
+        set
+    }
+
+    /// Sensible built-in routing for a known entry type; all fields otherwise.
+    fn default_for_type(entry_type: &str) -> Self {
+        match entry_type {
+            "article" => Self {
+                doi: true,
+                journal: true,
+                pages: true,
+                publisher: false,
+                editor: false,
+                booktitle: false,
+                series: false,
+                edition: false,
+                institution: false,
+                isbn_issn: true,
+                address: false,
+                url: true,
+            },
+            "inproceedings" | "proceedings" | "conference" | "incollection" | "inbook" => Self {
+                doi: true,
+                journal: false,
+                pages: true,
+                publisher: true,
+                editor: true,
+                booktitle: true,
+                series: true,
+                edition: false,
+                institution: false,
+                isbn_issn: true,
+                address: true,
+                url: true,
+            },
+            "book" => Self {
+                doi: true,
+                journal: false,
+                pages: false,
+                publisher: true,
+                editor: true,
+                booktitle: false,
+                series: true,
+                edition: true,
+                institution: false,
+                isbn_issn: true,
+                address: true,
+                url: true,
+            },
+            "phdthesis" | "mastersthesis" | "thesis" => Self {
+                doi: true,
+                journal: false,
+                pages: false,
+                publisher: false,
+                editor: false,
+                booktitle: false,
+                series: false,
+                edition: false,
+                institution: true,
+                isbn_issn: false,
+                address: true,
+                url: true,
+            },
+            _ => Self::default(),
+        }
+    }
+}
+
+/// Return the coarse type family an entry belongs to, for compatibility checks.
+fn entry_type_family(entry_type: &str) -> &'static str {
+    match entry_type.to_lowercase().as_str() {
+        "article" => "journal",
+        "book" | "inbook" | "incollection" | "booklet" => "book",
+        "inproceedings" | "proceedings" | "conference" => "proceedings",
+        "phdthesis" | "mastersthesis" | "thesis" => "thesis",
+        _ => "other",
+    }
+}
+
+/// Are two entry types compatible? Missing types or the `other` family never
+/// clash (mislabeled records are common); otherwise families must agree.
+fn types_compatible(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) if !a.is_empty() && !b.is_empty() => {
+            let (fa, fb) = (entry_type_family(a), entry_type_family(b));
+            fa == "other" || fb == "other" || fa == fb
+        }
+        _ => true,
+    }
+}
+
+/// Resolved per-type scoring configuration shared across a batch.
+struct ScoringProfiles {
+    default_weights: Weights,
+    type_weights: HashMap<String, Weights>,
+    type_bonus_fields: HashMap<String, BonusFieldSet>,
+    /// Multiplier applied to the total when subject/candidate types are
+    /// incompatible — a soft down-weight rather than a hard zero.
+    mismatch_factor: f64,
+    /// Whether `normalize` folds Unicode diacritics and ligatures. Carried on
+    /// the profile so the choice is threaded explicitly rather than held in
+    /// shared mutable state that concurrent batches could race on.
+    fold_diacritics: bool,
+}
+
+impl ScoringProfiles {
+    /// Build from the default weights plus optional per-type overrides.
+    fn new(
+        default_weights: Weights,
+        type_weights: Option<HashMap<String, Weights>>,
+        type_bonus_fields: Option<HashMap<String, Vec<String>>>,
+        mismatch_factor: f64,
+        fold_diacritics: bool,
+    ) -> Self {
+        let type_weights = type_weights
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(k, v)| (k.to_lowercase(), v))
+            .collect();
+        let type_bonus_fields = type_bonus_fields
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(k, v)| (k.to_lowercase(), BonusFieldSet::from_names(&v)))
+            .collect();
+        Self {
+            default_weights,
+            type_weights,
+            type_bonus_fields,
+            mismatch_factor,
+            fold_diacritics,
+        }
+    }
+
+    /// Weights for an entry type, falling back to the default profile.
+    fn weights_for(&self, entry_type: Option<&str>) -> &Weights {
+        entry_type
+            .and_then(|t| self.type_weights.get(&t.to_lowercase()))
+            .unwrap_or(&self.default_weights)
+    }
+
+    /// Bonus-field routing for an entry type: explicit override, then built-in
+    /// per-type default, then the all-fields default for untyped records.
+    fn bonus_fields_for(&self, entry_type: Option<&str>) -> BonusFieldSet {
+        match entry_type {
+            Some(t) => {
+                let key = t.to_lowercase();
+                self.type_bonus_fields
+                    .get(&key)
+                    .copied()
+                    .unwrap_or_else(|| BonusFieldSet::default_for_type(&key))
+            }
+            None => BonusFieldSet::default(),
+        }
+    }
 }
 
 /// Precomputed data for a subject to avoid recomputation per candidate
@@ -567,27 +1655,147 @@ struct PrecomputedSubject<'a> {
     data: &'a BibItemData,
     has_academic_prefix: bool,
     normalized_title: String,
+    /// Case-preserving normalized title for the fzf path (see [`normalize_cased`]).
+    normalized_title_cased: String,
     normalized_journal: Option<String>,
     normalized_publisher: Option<String>,
+    normalized_booktitle: Option<String>,
+    normalized_series: Option<String>,
+    normalized_institution: Option<String>,
+    normalized_address: Option<String>,
+}
+
+impl<'a> PrecomputedSubject<'a> {
+    fn new(data: &'a BibItemData, fold: bool) -> Self {
+        Self {
+            data,
+            has_academic_prefix: has_academic_prefix(&data.title),
+            normalized_title: normalize(&data.title, fold),
+            normalized_title_cased: normalize_cased(&data.title, fold),
+            normalized_journal: data.journal.as_ref().map(|j| normalize(j, fold)),
+            normalized_publisher: data.publisher.as_ref().map(|p| normalize(p, fold)),
+            normalized_booktitle: data.booktitle.as_ref().map(|b| normalize(b, fold)),
+            normalized_series: data.series.as_ref().map(|s| normalize(s, fold)),
+            normalized_institution: data
+                .institution
+                .as_ref()
+                .or(data.organization.as_ref())
+                .map(|i| normalize(i, fold)),
+            normalized_address: data.address.as_ref().map(|a| normalize(a, fold)),
+        }
+    }
+}
+
+/// Split a normalized string into word tokens.
+fn title_tokens(normalized: &str) -> Vec<String> {
+    normalized.split_whitespace().map(str::to_string).collect()
+}
+
+/// Does `haystack` contain `needle` as a contiguous run of tokens?
+fn contains_token_phrase(haystack: &[String], needle: &[String]) -> bool {
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return false;
+    }
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+/// Exactness (0-100): reward candidates containing the subject's query words as
+/// exact (non-fuzzy) tokens. Partial coverage scales up to 60; all words present
+/// earns 80; all words present as a contiguous phrase earns the full 100.
+fn score_exactness(subject_tokens: &[String], candidate_tokens: &[String]) -> f64 {
+    if subject_tokens.is_empty() || candidate_tokens.is_empty() {
+        return 0.0;
+    }
+    let cand_set: HashSet<&String> = candidate_tokens.iter().collect();
+    let present = subject_tokens
+        .iter()
+        .filter(|w| cand_set.contains(w))
+        .count();
+    if present == subject_tokens.len() {
+        if contains_token_phrase(candidate_tokens, subject_tokens) {
+            100.0
+        } else {
+            80.0
+        }
+    } else {
+        (present as f64 / subject_tokens.len() as f64) * 60.0
+    }
 }
 
-impl<'a> PrecomputedSubject<'a> {
-    fn new(data: &'a BibItemData) -> Self {
-        Self {
-            data,
-            has_academic_prefix: has_academic_prefix(&data.title),
-            normalized_title: normalize(&data.title),
-            normalized_journal: data.journal.as_ref().map(|j| normalize(j)),
-            normalized_publisher: data.publisher.as_ref().map(|p| normalize(p)),
+/// Proximity (0-100): find the shortest candidate token span covering all
+/// matched query words; a tighter span scores higher. Weighted by the fraction
+/// of query words present so partial matches cannot beat complete tight ones.
+fn score_proximity(subject_tokens: &[String], candidate_tokens: &[String]) -> f64 {
+    if subject_tokens.is_empty() || candidate_tokens.is_empty() {
+        return 0.0;
+    }
+    // Distinct matched query words and their occurrence positions in candidate.
+    let mut occurrences: Vec<(usize, usize)> = Vec::new(); // (position, word id)
+    let mut matched_words: Vec<&String> = Vec::new();
+    for w in subject_tokens {
+        if matched_words.iter().any(|m| *m == w) {
+            continue;
+        }
+        let positions: Vec<usize> = candidate_tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| *c == w)
+            .map(|(i, _)| i)
+            .collect();
+        if positions.is_empty() {
+            continue;
+        }
+        let id = matched_words.len();
+        matched_words.push(w);
+        occurrences.extend(positions.into_iter().map(|p| (p, id)));
+    }
+    let distinct = matched_words.len();
+    if distinct == 0 {
+        return 0.0;
+    }
+    let query_words = subject_tokens.iter().collect::<HashSet<_>>().len();
+    let coverage = distinct as f64 / query_words as f64;
+    if distinct == 1 {
+        return coverage * 100.0;
+    }
+
+    // Sliding window over occurrences sorted by position to find the minimum
+    // span that contains at least one occurrence of every matched word.
+    occurrences.sort_by_key(|&(pos, _)| pos);
+    let mut counts = vec![0usize; distinct];
+    let mut have = 0usize;
+    let mut left = 0usize;
+    let mut best_span = usize::MAX;
+    for right in 0..occurrences.len() {
+        let (_, id) = occurrences[right];
+        if counts[id] == 0 {
+            have += 1;
+        }
+        counts[id] += 1;
+        while have == distinct {
+            let span = occurrences[right].0 - occurrences[left].0 + 1;
+            if span < best_span {
+                best_span = span;
+            }
+            let (_, lid) = occurrences[left];
+            counts[lid] -= 1;
+            if counts[lid] == 0 {
+                have -= 1;
+            }
+            left += 1;
         }
     }
+    // A contiguous span equals the number of matched words; looser spans decay.
+    let tightness = distinct as f64 / best_span as f64;
+    coverage * tightness * 100.0
 }
 
-/// Score a single candidate against a subject with configurable weights
+/// Score a single candidate against a subject, routing weights and bonus
+/// fields by the subject's entry type.
 fn score_candidate(
     subject: &BibItemData,
     candidate: &BibItemData,
-    weights: &Weights,
+    profiles: &ScoringProfiles,
 ) -> MatchResult {
     // Academic prefix gate: if one title has prefix and other doesn't, automatic non-match
     let subject_has_prefix = has_academic_prefix(&subject.title);
@@ -600,15 +1808,40 @@ fn score_candidate(
             author_score: 0.0,
             date_score: 0.0,
             bonus_score: 0.0,
+            exactness_score: 0.0,
+            proximity_score: 0.0,
+            ranking_bucket: None,
         };
     }
 
-    let title_score = score_title(&subject.title, &candidate.title, weights.title);
-    let author_score = score_author(&subject.author, &candidate.author, weights.author);
+    let entry_type = subject.entry_type.as_deref();
+    let weights = profiles.weights_for(entry_type);
+    let fields = profiles.bonus_fields_for(entry_type);
+    let fold = profiles.fold_diacritics;
+
+    let title_score = score_title(
+        &subject.title,
+        &candidate.title,
+        subject.language.as_deref(),
+        candidate.language.as_deref(),
+        title_algorithm_from(weights.title_algorithm.as_deref()),
+        weights.title,
+        fold,
+    );
+    let author_score = score_author(&subject.author, &candidate.author, weights.author, fold);
     let date_score = score_date(subject.year, candidate.year, weights.date);
-    let bonus_score = score_bonus(subject, candidate, weights.bonus);
+    let bonus_score = score_bonus(subject, candidate, &fields, weights.bonus, fold);
 
-    let total_score = title_score + author_score + date_score + bonus_score;
+    let subject_tokens = title_tokens(&normalize(&subject.title, fold));
+    let candidate_tokens = title_tokens(&normalize(&candidate.title, fold));
+    let exactness_score = score_exactness(&subject_tokens, &candidate_tokens) * weights.exactness;
+    let proximity_score = score_proximity(&subject_tokens, &candidate_tokens) * weights.proximity;
+
+    let mut total_score =
+        title_score + author_score + date_score + bonus_score + exactness_score + proximity_score;
+    if !types_compatible(entry_type, candidate.entry_type.as_deref()) {
+        total_score *= profiles.mismatch_factor;
+    }
 
     MatchResult {
         candidate_index: candidate.index,
@@ -617,6 +1850,9 @@ fn score_candidate(
         author_score,
         date_score,
         bonus_score,
+        exactness_score,
+        proximity_score,
+        ranking_bucket: None,
     }
 }
 
@@ -624,7 +1860,7 @@ fn score_candidate(
 fn score_candidate_precomputed(
     subject: &PrecomputedSubject,
     candidate: &BibItemData,
-    weights: &Weights,
+    profiles: &ScoringProfiles,
 ) -> MatchResult {
     // Academic prefix gate using precomputed subject prefix
     let candidate_has_prefix = has_academic_prefix(&candidate.title);
@@ -636,17 +1872,43 @@ fn score_candidate_precomputed(
             author_score: 0.0,
             date_score: 0.0,
             bonus_score: 0.0,
+            exactness_score: 0.0,
+            proximity_score: 0.0,
+            ranking_bucket: None,
         };
     }
 
+    let entry_type = subject.data.entry_type.as_deref();
+    let weights = profiles.weights_for(entry_type);
+    let fields = profiles.bonus_fields_for(entry_type);
+    let fold = profiles.fold_diacritics;
+
     // Use precomputed normalized title
-    let title_score =
-        score_title_prenorm(&subject.normalized_title, &candidate.title, weights.title);
-    let author_score = score_author(&subject.data.author, &candidate.author, weights.author);
+    let title_score = score_title_prenorm(
+        &subject.normalized_title,
+        &subject.normalized_title_cased,
+        &candidate.title,
+        subject.data.language.as_deref(),
+        candidate.language.as_deref(),
+        title_algorithm_from(weights.title_algorithm.as_deref()),
+        weights.title,
+        fold,
+    );
+    let author_score = score_author(&subject.data.author, &candidate.author, weights.author, fold);
     let date_score = score_date(subject.data.year, candidate.year, weights.date);
-    let bonus_score = score_bonus_precomputed(subject, candidate, weights.bonus);
+    let bonus_score = score_bonus_precomputed(subject, candidate, &fields, weights.bonus, fold);
+
+    // Reuse the precomputed normalized subject title for tokenization
+    let subject_tokens = title_tokens(&subject.normalized_title);
+    let candidate_tokens = title_tokens(&normalize(&candidate.title, fold));
+    let exactness_score = score_exactness(&subject_tokens, &candidate_tokens) * weights.exactness;
+    let proximity_score = score_proximity(&subject_tokens, &candidate_tokens) * weights.proximity;
 
-    let total_score = title_score + author_score + date_score + bonus_score;
+    let mut total_score =
+        title_score + author_score + date_score + bonus_score + exactness_score + proximity_score;
+    if !types_compatible(entry_type, candidate.entry_type.as_deref()) {
+        total_score *= profiles.mismatch_factor;
+    }
 
     MatchResult {
         candidate_index: candidate.index,
@@ -655,6 +1917,9 @@ fn score_candidate_precomputed(
         author_score,
         date_score,
         bonus_score,
+        exactness_score,
+        proximity_score,
+        ranking_bucket: None,
     }
 }
 
@@ -664,7 +1929,7 @@ fn find_top_matches(
     candidates: &[BibItemData],
     top_n: usize,
     min_score: f64,
-    weights: &Weights,
+    profiles: &ScoringProfiles,
 ) -> Vec<MatchResult> {
     // Quick DOI check first
     if let Some(ref subject_doi) = subject.doi {
@@ -672,7 +1937,7 @@ fn find_top_matches(
             for candidate in candidates {
                 if let Some(ref cand_doi) = candidate.doi {
                     if subject_doi == cand_doi {
-                        return vec![score_candidate(subject, candidate, weights)];
+                        return vec![score_candidate(subject, candidate, profiles)];
                     }
                 }
             }
@@ -683,7 +1948,7 @@ fn find_top_matches(
     let mut heap: BinaryHeap<MatchResult> = BinaryHeap::new();
 
     for candidate in candidates {
-        let result = score_candidate(subject, candidate, weights);
+        let result = score_candidate(subject, candidate, profiles);
         if result.total_score >= min_score {
             heap.push(result);
         }
@@ -723,11 +1988,93 @@ struct BlockingIndexData {
     decade_index: HashMap<i32, Vec<usize>>,
 }
 
-/// Get candidate indices for a subject using the blocking index
+/// Graduated, Meilisearch-style typo budget for a word of `len` chars, capped
+/// at `max_typos`: 0 typos for words ≤ 4 chars, 1 for 5–8, 2 for longer.
+fn typo_budget(len: usize, max_typos: usize) -> usize {
+    let base = if len <= 4 {
+        0
+    } else if len <= 8 {
+        1
+    } else {
+        2
+    };
+    base.min(max_typos)
+}
+
+/// A word plus all its deletion-neighborhood variants with up to `budget`
+/// characters removed. Two words within edit distance `d` share a variant once
+/// both sides are expanded to `d` deletions, which recovers typo'd matches
+/// without computing Levenshtein distance per pair.
+fn deletion_variants(word: &str, budget: usize) -> HashSet<String> {
+    let mut out = HashSet::new();
+    out.insert(word.to_string());
+    if budget == 0 {
+        return out;
+    }
+    let mut frontier: HashSet<Vec<char>> = HashSet::new();
+    frontier.insert(word.chars().collect());
+    for _ in 0..budget {
+        let mut next: HashSet<Vec<char>> = HashSet::new();
+        for w in &frontier {
+            if w.len() <= 1 {
+                continue;
+            }
+            for i in 0..w.len() {
+                let mut v = w.clone();
+                v.remove(i);
+                let s: String = v.iter().collect();
+                if out.insert(s) {
+                    next.insert(v);
+                }
+            }
+        }
+        frontier = next;
+    }
+    out
+}
+
+/// Normalized word tokens of a field, for typo-tolerant term indexing.
+fn index_terms(s: &str, fold: bool) -> Vec<String> {
+    normalize(s, fold)
+        .split_whitespace()
+        .map(|w| w.to_string())
+        .collect()
+}
+
+/// Build a deletion-neighborhood term index mapping each variant string to the
+/// candidate indices whose title or author contains the originating word.
+fn build_typo_index(
+    candidates: &[BibItemData],
+    max_typos: usize,
+    fold: bool,
+) -> HashMap<String, Vec<usize>> {
+    let mut map: HashMap<String, Vec<usize>> = HashMap::new();
+    for cand in candidates {
+        let mut words: HashSet<String> = HashSet::new();
+        words.extend(index_terms(&cand.title, fold));
+        words.extend(index_terms(&cand.author, fold));
+        for w in words {
+            let budget = typo_budget(w.chars().count(), max_typos);
+            for variant in deletion_variants(&w, budget) {
+                let entry = map.entry(variant).or_default();
+                if entry.last() != Some(&cand.index) {
+                    entry.push(cand.index);
+                }
+            }
+        }
+    }
+    map
+}
+
+/// Get candidate indices for a subject using the blocking index, optionally
+/// widened by typo-tolerant term matches from `typo_index`.
 fn get_candidate_indices(
     subject: &BibItemData,
     index: &BlockingIndexData,
+    typo_index: &HashMap<String, Vec<usize>>,
+    max_typos: usize,
     num_candidates: usize,
+    fold: bool,
 ) -> Vec<usize> {
     // DOI exact match - return immediately
     if let Some(ref doi) = subject.doi {
@@ -767,6 +2114,22 @@ fn get_candidate_indices(
         }
     }
 
+    // Typo-tolerant term matches: recover candidates whose title/author word
+    // is within the graduated edit-distance budget of a subject word.
+    if max_typos > 0 && !typo_index.is_empty() {
+        let mut subject_words: HashSet<String> = HashSet::new();
+        subject_words.extend(index_terms(&subject.title, fold));
+        subject_words.extend(index_terms(&subject.author, fold));
+        for w in subject_words {
+            let budget = typo_budget(w.chars().count(), max_typos);
+            for variant in deletion_variants(&w, budget) {
+                if let Some(idxs) = typo_index.get(&variant) {
+                    indices.extend(idxs);
+                }
+            }
+        }
+    }
+
     // Fallback to all if no candidates found
     if indices.is_empty() {
         return (0..num_candidates).collect();
@@ -776,21 +2139,40 @@ fn get_candidate_indices(
 }
 
 /// Batch score multiple subjects against candidates in parallel.
+///
+/// `weights` is the default profile; `type_weights` and `type_bonus_fields`
+/// supply per-entry-type overrides, and `type_mismatch_penalty` is the
+/// multiplier applied when subject and candidate entry types are incompatible.
+/// `fold_diacritics` controls Unicode accent/ligature folding in `normalize`;
+/// disable it for corpora where diacritics are semantically significant.
 #[pyfunction]
+#[pyo3(signature = (subjects, candidates, top_n, min_score, weights, type_weights=None, type_bonus_fields=None, type_mismatch_penalty=0.5, fold_diacritics=true))]
+#[allow(clippy::too_many_arguments)]
 fn score_batch(
     subjects: Vec<BibItemData>,
     candidates: Vec<BibItemData>,
     top_n: usize,
     min_score: f64,
     weights: Weights,
+    type_weights: Option<HashMap<String, Weights>>,
+    type_bonus_fields: Option<HashMap<String, Vec<String>>>,
+    type_mismatch_penalty: f64,
+    fold_diacritics: bool,
 ) -> Vec<SubjectMatchResult> {
     let candidates_len = candidates.len();
+    let profiles = ScoringProfiles::new(
+        weights,
+        type_weights,
+        type_bonus_fields,
+        type_mismatch_penalty,
+        fold_diacritics,
+    );
 
     subjects
         .par_iter()
         .enumerate()
         .map(|(idx, subject)| {
-            let matches = find_top_matches(subject, &candidates, top_n, min_score, &weights);
+            let matches = find_top_matches(subject, &candidates, top_n, min_score, &profiles);
             SubjectMatchResult {
                 subject_index: idx,
                 matches,
@@ -808,24 +2190,60 @@ fn find_top_matches_indexed(
     doi_map: &HashMap<&str, usize>,
     top_n: usize,
     min_score: f64,
-    weights: &Weights,
+    profiles: &ScoringProfiles,
+    rules: &[RankingRule],
 ) -> (Vec<MatchResult>, usize) {
     // Quick DOI check using prebuilt map (O(1) instead of O(n))
     if let Some(ref subject_doi) = subject.data.doi {
         if !subject_doi.is_empty() {
             if let Some(&cand_idx) = doi_map.get(subject_doi.as_str()) {
-                let result = score_candidate_precomputed(subject, &candidates[cand_idx], weights);
+                let mut result =
+                    score_candidate_precomputed(subject, &candidates[cand_idx], profiles);
+                if !rules.is_empty() {
+                    result.ranking_bucket = Some(ranking_buckets(
+                        rules,
+                        subject.data,
+                        &candidates[cand_idx],
+                        &result,
+                    ));
+                }
                 return (vec![result], 1);
             }
         }
     }
 
-    // Score only the filtered candidates
+    let searched = candidate_indices.len();
+
+    // Ranking-rule strategy: score all qualifying candidates, then lexicographic
+    // bucket sort down the rule pipeline (weighted total is the final tiebreak).
+    if !rules.is_empty() {
+        let mut scored: Vec<MatchResult> = Vec::new();
+        for &cand_idx in candidate_indices {
+            if cand_idx < candidates.len() {
+                let mut result =
+                    score_candidate_precomputed(subject, &candidates[cand_idx], profiles);
+                if result.total_score >= min_score {
+                    result.ranking_bucket =
+                        Some(ranking_buckets(rules, subject.data, &candidates[cand_idx], &result));
+                    scored.push(result);
+                }
+            }
+        }
+        scored.sort_by(|a, b| {
+            b.ranking_bucket
+                .cmp(&a.ranking_bucket)
+                .then_with(|| b.cmp(a))
+        });
+        scored.truncate(top_n);
+        return (scored, searched);
+    }
+
+    // Weighted-sum strategy (default): a max-heap on the blended total score.
     let mut heap: BinaryHeap<MatchResult> = BinaryHeap::new();
 
     for &cand_idx in candidate_indices {
         if cand_idx < candidates.len() {
-            let result = score_candidate_precomputed(subject, &candidates[cand_idx], weights);
+            let result = score_candidate_precomputed(subject, &candidates[cand_idx], profiles);
             if result.total_score >= min_score {
                 heap.push(result);
             }
@@ -833,7 +2251,6 @@ fn find_top_matches_indexed(
     }
 
     // Extract top N
-    let searched = candidate_indices.len();
     let mut results: Vec<MatchResult> = Vec::with_capacity(top_n.min(heap.len()));
     for _ in 0..top_n {
         if let Some(result) = heap.pop() {
@@ -849,6 +2266,8 @@ fn find_top_matches_indexed(
 /// Batch score with blocking index - filters candidates per subject for massive speedup.
 /// This is the primary entry point for fuzzy matching.
 #[pyfunction]
+#[pyo3(signature = (subjects, candidates, index, top_n, min_score, weights, type_weights=None, type_bonus_fields=None, type_mismatch_penalty=0.5, fold_diacritics=true, max_typos=2, ranking_rules=None))]
+#[allow(clippy::too_many_arguments)]
 fn score_batch_indexed(
     subjects: Vec<BibItemData>,
     candidates: Vec<BibItemData>,
@@ -856,8 +2275,33 @@ fn score_batch_indexed(
     top_n: usize,
     min_score: f64,
     weights: Weights,
+    type_weights: Option<HashMap<String, Weights>>,
+    type_bonus_fields: Option<HashMap<String, Vec<String>>>,
+    type_mismatch_penalty: f64,
+    fold_diacritics: bool,
+    max_typos: usize,
+    ranking_rules: Option<Vec<String>>,
 ) -> Vec<SubjectMatchResult> {
     let num_candidates = candidates.len();
+    let profiles = ScoringProfiles::new(
+        weights,
+        type_weights,
+        type_bonus_fields,
+        type_mismatch_penalty,
+        fold_diacritics,
+    );
+
+    // Resolve the ranking-rule pipeline; empty selects the weighted-sum strategy.
+    let rules = ranking_rules
+        .map(|names| ranking_rules_from(&names))
+        .unwrap_or_default();
+
+    // Build the typo-tolerant term index once per batch (empty when disabled).
+    let typo_index = if max_typos > 0 {
+        build_typo_index(&candidates, max_typos, fold_diacritics)
+    } else {
+        HashMap::new()
+    };
 
     // Build DOI map once for O(1) lookups
     let doi_map: HashMap<&str, usize> = candidates
@@ -875,10 +2319,17 @@ fn score_batch_indexed(
         .enumerate()
         .map(|(idx, subject)| {
             // Precompute subject data once
-            let precomputed = PrecomputedSubject::new(subject);
+            let precomputed = PrecomputedSubject::new(subject, fold_diacritics);
 
             // Get filtered candidate indices from blocking index
-            let candidate_indices = get_candidate_indices(subject, &index, num_candidates);
+            let candidate_indices = get_candidate_indices(
+                subject,
+                &index,
+                &typo_index,
+                max_typos,
+                num_candidates,
+                fold_diacritics,
+            );
 
             // Score only filtered candidates
             let (matches, searched) = find_top_matches_indexed(
@@ -888,7 +2339,8 @@ fn score_batch_indexed(
                 &doi_map,
                 top_n,
                 min_score,
-                &weights,
+                &profiles,
+                &rules,
             );
 
             SubjectMatchResult {
@@ -908,6 +2360,200 @@ fn hello_rust() -> PyResult<String> {
     Ok("Hello from Rust!".to_string())
 }
 
+// === Scoring configuration loading and validation ===
+
+/// Raw, untrusted weights as they appear in a config file. `deny_unknown_fields`
+/// rejects typos like `titel` with a precise error instead of silently dropping
+/// them; ranges are checked separately in [`validate_config`].
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawWeights {
+    title: f64,
+    author: f64,
+    date: f64,
+    bonus: f64,
+    #[serde(default)]
+    exactness: f64,
+    #[serde(default)]
+    proximity: f64,
+    #[serde(default)]
+    title_algorithm: Option<String>,
+}
+
+/// One date-tolerance tier: years apart and the score awarded.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawDateTier {
+    max_year_diff: u32,
+    score: f64,
+}
+
+/// Raw scoring configuration as read from JSON or TOML.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawConfig {
+    weights: RawWeights,
+    #[serde(default)]
+    min_score: Option<f64>,
+    #[serde(default)]
+    top_n: Option<usize>,
+    #[serde(default)]
+    date_tolerance: Option<Vec<RawDateTier>>,
+    #[serde(default)]
+    academic_prefixes: Option<Vec<String>>,
+}
+
+/// Validated scoring configuration returned to Python: the per-component
+/// weights and `min_score` / `top_n` thresholds feed straight into
+/// `score_batch` / `score_batch_indexed`. `date_tolerance` (range-checked) and
+/// `academic_prefixes` (taken as given) are parsed here but are applied by the
+/// caller rather than consumed by `score_batch`: pass `academic_prefixes` to
+/// [`set_academic_prefixes`] to install the prefix gate. `date_tolerance` is
+/// surfaced for the caller's own use (inspection, logging); the Rust scorer
+/// always uses the built-in `score_date` tiers and does not read it.
+#[pyclass]
+struct ScoringConfig {
+    #[pyo3(get)]
+    title: f64,
+    #[pyo3(get)]
+    author: f64,
+    #[pyo3(get)]
+    date: f64,
+    #[pyo3(get)]
+    bonus: f64,
+    #[pyo3(get)]
+    exactness: f64,
+    #[pyo3(get)]
+    proximity: f64,
+    #[pyo3(get)]
+    title_algorithm: Option<String>,
+    #[pyo3(get)]
+    min_score: f64,
+    #[pyo3(get)]
+    top_n: usize,
+    #[pyo3(get)]
+    date_tolerance: Vec<(u32, f64)>,
+    #[pyo3(get)]
+    academic_prefixes: Vec<String>,
+}
+
+/// Reject a weight that falls outside the unit interval, with a deserr-style
+/// message naming the offending field.
+fn check_unit_weight(field: &str, value: f64) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&value) {
+        Err(format!(
+            "field `{field}` expected a number between 0.0 and 1.0, found {value}"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parse a config string as JSON, falling back to TOML (or going straight to
+/// TOML when the source is a `.toml` file), surfacing the parser's line info.
+fn parse_config(text: &str, prefer_toml: bool) -> Result<RawConfig, String> {
+    if prefer_toml {
+        return toml::from_str(text).map_err(|e| format!("invalid TOML config: {e}"));
+    }
+    match serde_json::from_str::<RawConfig>(text) {
+        Ok(cfg) => Ok(cfg),
+        Err(json_err) => toml::from_str::<RawConfig>(text).map_err(|_| {
+            format!(
+                "invalid config: {json_err} (at line {} column {})",
+                json_err.line(),
+                json_err.column()
+            )
+        }),
+    }
+}
+
+/// Validate ranges and fill defaults, turning a `RawConfig` into a
+/// `ScoringConfig` or a precise error message.
+fn validate_config(raw: RawConfig) -> Result<ScoringConfig, String> {
+    check_unit_weight("weights.title", raw.weights.title)?;
+    check_unit_weight("weights.author", raw.weights.author)?;
+    check_unit_weight("weights.date", raw.weights.date)?;
+    check_unit_weight("weights.bonus", raw.weights.bonus)?;
+    check_unit_weight("weights.exactness", raw.weights.exactness)?;
+    check_unit_weight("weights.proximity", raw.weights.proximity)?;
+
+    let min_score = raw.min_score.unwrap_or(0.0);
+    if min_score < 0.0 {
+        return Err(format!(
+            "field `min_score` expected a non-negative number, found {min_score}"
+        ));
+    }
+
+    let top_n = raw.top_n.unwrap_or(10);
+    if top_n == 0 {
+        return Err("field `top_n` expected a positive integer, found 0".to_string());
+    }
+
+    let date_tolerance = raw
+        .date_tolerance
+        .unwrap_or_default()
+        .into_iter()
+        .map(|tier| {
+            if (0.0..=100.0).contains(&tier.score) {
+                Ok((tier.max_year_diff, tier.score))
+            } else {
+                Err(format!(
+                    "field `date_tolerance.score` expected a number between 0.0 and 100.0, found {}",
+                    tier.score
+                ))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ScoringConfig {
+        title: raw.weights.title,
+        author: raw.weights.author,
+        date: raw.weights.date,
+        bonus: raw.weights.bonus,
+        exactness: raw.weights.exactness,
+        proximity: raw.weights.proximity,
+        title_algorithm: raw.weights.title_algorithm,
+        min_score,
+        top_n,
+        date_tolerance,
+        academic_prefixes: raw.academic_prefixes.unwrap_or_default(),
+    })
+}
+
+/// Load and validate a scoring configuration from a file path or an inline
+/// JSON/TOML string. Returns a `ScoringConfig` with validated weights and
+/// thresholds, or a `ValueError` describing the first offending field.
+#[pyfunction]
+fn load_config(path_or_str: &str) -> PyResult<ScoringConfig> {
+    let (text, prefer_toml) = if Path::new(path_or_str).is_file() {
+        let content = std::fs::read_to_string(path_or_str).map_err(|e| {
+            PyValueError::new_err(format!("could not read config file `{path_or_str}`: {e}"))
+        })?;
+        (content, path_or_str.ends_with(".toml"))
+    } else {
+        (path_or_str.to_string(), false)
+    };
+
+    let raw = parse_config(&text, prefer_toml).map_err(PyValueError::new_err)?;
+    validate_config(raw).map_err(PyValueError::new_err)
+}
+
+/// Override the academic-prefix gate phrases (see [`has_academic_prefix`]) with
+/// a list sourced from config. Phrases are matched case-insensitively. Passing
+/// an empty list clears the override and restores the compiled-in
+/// [`ACADEMIC_REVIEW_PREFIXES`] defaults, so callers can reset the gate between
+/// runs without restarting the interpreter.
+#[pyfunction]
+fn set_academic_prefixes(prefixes: Vec<String>) {
+    if let Ok(mut guard) = CUSTOM_ACADEMIC_PREFIXES.write() {
+        *guard = if prefixes.is_empty() {
+            None
+        } else {
+            Some(prefixes.iter().map(|p| p.to_lowercase()).collect())
+        };
+    }
+}
+
 /// A Python module implemented in Rust.
 #[pymodule]
 fn _rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -918,6 +2564,10 @@ fn _rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(token_sort_ratio, m)?)?;
     m.add_function(wrap_pyfunction!(score_batch, m)?)?;
     m.add_function(wrap_pyfunction!(score_batch_indexed, m)?)?;
+    // Configuration loading
+    m.add_class::<ScoringConfig>()?;
+    m.add_function(wrap_pyfunction!(load_config, m)?)?;
+    m.add_function(wrap_pyfunction!(set_academic_prefixes, m)?)?;
     Ok(())
 }
 
@@ -1035,23 +2685,504 @@ mod tests {
     #[test]
     fn test_check_initials_match() {
         // "E. M. Adams" vs "Ernest M. Adams" should match
-        assert!(check_initials_match("E. M. Adams", "Ernest M. Adams"));
-        assert!(check_initials_match("J. Smith", "John Smith"));
+        assert!(check_initials_match("E. M. Adams", "Ernest M. Adams", true));
+        assert!(check_initials_match("J. Smith", "John Smith", true));
         // Different surnames should not match
-        assert!(!check_initials_match("E. Adams", "Ernest Jones"));
+        assert!(!check_initials_match("E. Adams", "Ernest Jones", true));
         // Both full names should not trigger (handled by fuzzy)
-        assert!(!check_initials_match("Ernest Adams", "Ernest Adams"));
+        assert!(!check_initials_match("Ernest Adams", "Ernest Adams", true));
         // Both initials should not trigger
-        assert!(!check_initials_match("E. Adams", "E. Adams"));
+        assert!(!check_initials_match("E. Adams", "E. Adams", true));
+    }
+
+    // Script-aware (CJK) title matching tests
+    #[test]
+    fn test_normalize_folds_fullwidth_digits() {
+        assert_eq!(normalize("２０２４", true), "2024");
+        assert_eq!(normalize("ＡＢＣ", true), "abc");
+    }
+
+    #[test]
+    fn test_normalize_folds_diacritics_and_ligatures() {
+        // Combining marks stripped, ligatures expanded, special letters mapped
+        assert_eq!(normalize("Gödel", true), "godel");
+        assert_eq!(normalize("Œuvre", true), "oeuvre");
+        assert_eq!(normalize("Straße", true), "strasse");
+        assert_eq!(normalize("Gdańsk", true), "gdansk");
+        assert_eq!(normalize("Gödel", true), normalize("Godel", true));
+        // With folding disabled, diacritics are preserved (only case/whitespace fold)
+        assert_eq!(normalize("Gödel", false), "gödel");
+    }
+
+    #[test]
+    fn test_fold_unicode_leaves_cjk_untouched() {
+        // CJK code points carry no combining marks and must survive folding
+        assert_eq!(fold_unicode("認識論"), "認識論");
+    }
+
+    #[test]
+    fn test_is_cjk_dominant() {
+        assert!(is_cjk_dominant("知识与信念"));
+        assert!(is_cjk_dominant("認識論の研究"));
+        assert!(!is_cjk_dominant("Theory of Knowledge"));
+        // A mostly-Latin string with one stray ideograph stays on the Latin path
+        assert!(!is_cjk_dominant("Knowledge 知 and belief"));
+    }
+
+    #[test]
+    fn test_trigram_dice_score() {
+        assert!((trigram_dice_score("知识与信念", "知识与信念") - 100.0).abs() < 0.001);
+        // Shared leading characters score partially
+        let partial = trigram_dice_score("知识与信念的理论", "知识与信念");
+        assert!(partial > 0.0 && partial < 100.0);
+        // Disjoint strings score zero
+        assert!((trigram_dice_score("知识与信念", "天气预报表") - 0.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_trigram_dice_short_fallback() {
+        // Fewer than 3 code points falls back to exact equality
+        assert!((trigram_dice_score("知识", "知识") - 100.0).abs() < 0.001);
+        assert!((trigram_dice_score("知识", "信念") - 0.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_score_title_cjk_path() {
+        // Identical CJK titles score highly via the Dice path (+ contains bonus)
+        let score = score_title(
+            "知识与信念的理论",
+            "知识与信念的理论",
+            None,
+            None,
+            TitleAlgorithm::TokenSort,
+            1.0,
+            true,
+        );
+        assert!(score > 100.0);
+        // Language tag forces the CJK path even for romanized input
+        let tagged = score_title(
+            "renshilun",
+            "renshilun",
+            Some("zh"),
+            Some("zh"),
+            TitleAlgorithm::TokenSort,
+            1.0,
+            true,
+        );
+        assert!(tagged > 100.0);
     }
 
     #[test]
     fn test_score_author_with_initials() {
         // With initials matching, should get +50 bonus even if fuzzy is < 85
-        let score_with_initials = score_author("E. M. Adams", "Ernest M. Adams", 1.0);
-        let score_without_match = score_author("E. M. Adams", "John Smith", 1.0);
+        let score_with_initials = score_author("E. M. Adams", "Ernest M. Adams", 1.0, true);
+        let score_without_match = score_author("E. M. Adams", "John Smith", 1.0, true);
         assert!(score_with_initials > score_without_match);
         // The initials bonus is +50
         assert!(score_with_initials >= 50.0);
     }
+
+    // Exactness and proximity title signal tests
+    fn toks(s: &str) -> Vec<String> {
+        title_tokens(&normalize(s, true))
+    }
+
+    #[test]
+    fn test_score_exactness_phrase_beats_scatter() {
+        let query = toks("belief and knowledge");
+        let phrase = score_exactness(&query, &toks("belief and knowledge in epistemology"));
+        let scattered = score_exactness(&query, &toks("belief in justified true knowledge and more"));
+        let partial = score_exactness(&query, &toks("belief systems overview"));
+        assert!((phrase - 100.0).abs() < 0.001);
+        assert!(scattered > partial);
+        assert!(scattered < phrase);
+    }
+
+    #[test]
+    fn test_score_proximity_tighter_span_scores_higher() {
+        let query = toks("belief knowledge");
+        let close = score_proximity(&query, &toks("belief and knowledge"));
+        let far = score_proximity(
+            &query,
+            &toks("belief is a long standing topic that eventually connects to knowledge"),
+        );
+        assert!(close > far);
+        assert!(far > 0.0);
+    }
+
+    #[test]
+    fn test_score_proximity_coverage() {
+        let query = toks("belief knowledge");
+        // One of two query words present: coverage 0.5 -> 50
+        assert!((score_proximity(&query, &toks("belief only")) - 50.0).abs() < 0.001);
+        // No query words present -> 0
+        assert!((score_proximity(&query, &toks("entirely different")) - 0.0).abs() < 0.001);
+    }
+
+    // fzf positional title scorer tests
+    #[test]
+    fn test_fzf_score_identical() {
+        assert!((fzf_score("theory of knowledge", "theory of knowledge") - 100.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_fzf_score_subtitle() {
+        // The full query aligns consecutively at the start of the longer title
+        let score = fzf_score("knowledge", "knowledge: a very short introduction");
+        assert!(score > 90.0);
+    }
+
+    #[test]
+    fn test_fzf_score_out_of_order_penalized() {
+        // fzf rewards in-order, word-aligned hits; reordered words score lower
+        let aligned = fzf_score("theory of knowledge", "theory of knowledge and belief");
+        let reordered = fzf_score("theory of knowledge", "knowledge theory");
+        assert!(aligned > reordered);
+    }
+
+    #[test]
+    fn test_fzf_score_no_match() {
+        assert!((fzf_score("abc", "xyz") - 0.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_fzf_score_case_mismatch_penalized() {
+        // An uppercase-source acronym matched against lowercase text keeps the
+        // subsequence but pays the case-mismatch penalty, so it scores below a
+        // same-case match. Requires fzf to see case-preserving input.
+        let same_case = fzf_score("DNA", "DNA Replication");
+        let folded_case = fzf_score("DNA", "dna replication");
+        assert!(same_case > folded_case);
+    }
+
+    #[test]
+    fn test_fzf_prefilter_ordered_subsequence() {
+        assert!(fzf_prefilter(b"tok", b"theory of knowledge"));
+        // Right chars, wrong order — rejected before the DP
+        assert!(!fzf_prefilter(b"kot", b"theory of knowledge"));
+        assert!(!fzf_prefilter(b"xyz", b"theory of knowledge"));
+    }
+
+    #[test]
+    fn test_fzf_ratio_alias_selects_fzf() {
+        assert_eq!(title_algorithm_from(Some("fzf_ratio")), TitleAlgorithm::Fzf);
+    }
+
+    // Typo-tolerant blocking retrieval tests
+    #[test]
+    fn test_typo_budget_tiers() {
+        assert_eq!(typo_budget(4, 2), 0);
+        assert_eq!(typo_budget(6, 2), 1);
+        assert_eq!(typo_budget(12, 2), 2);
+        // Capped by the caller's max
+        assert_eq!(typo_budget(12, 1), 1);
+        assert_eq!(typo_budget(12, 0), 0);
+    }
+
+    #[test]
+    fn test_deletion_variants() {
+        let v = deletion_variants("cat", 1);
+        assert!(v.contains("cat"));
+        assert!(v.contains("ca") && v.contains("ct") && v.contains("at"));
+        // Budget 0 yields only the word itself
+        assert_eq!(deletion_variants("cat", 0).len(), 1);
+    }
+
+    // Ranking-rule pipeline tests
+    #[test]
+    fn test_ranking_rule_parsing() {
+        assert_eq!(ranking_rule_from("ExactDoi"), Some(RankingRule::ExactDoi));
+        assert_eq!(ranking_rule_from("title"), Some(RankingRule::TitleTypo));
+        assert_eq!(ranking_rule_from("date"), Some(RankingRule::Date));
+        assert_eq!(ranking_rule_from("nonsense"), None);
+        // Unknown names are dropped, order preserved
+        let rules = ranking_rules_from(&[
+            "doi".to_string(),
+            "bogus".to_string(),
+            "author".to_string(),
+        ]);
+        assert_eq!(rules, vec![RankingRule::ExactDoi, RankingRule::Author]);
+    }
+
+    #[test]
+    fn test_rule_bucket_exact_doi_dominates() {
+        let subject = BibItemData {
+            index: 0,
+            title: "T".to_string(),
+            author: "A".to_string(),
+            year: None,
+            doi: Some("10.1/x".to_string()),
+            journal: None,
+            volume: None,
+            number: None,
+            pages: None,
+            publisher: None,
+            language: None,
+            entry_type: None,
+            editor: None,
+            booktitle: None,
+            series: None,
+            edition: None,
+            institution: None,
+            organization: None,
+            isbn: None,
+            issn: None,
+            address: None,
+            url: None,
+            urldate: None,
+        };
+        let mut matching = subject.clone();
+        matching.index = 1;
+        let mut other = subject.clone();
+        other.index = 2;
+        other.doi = Some("10.1/y".to_string());
+        let dummy = MatchResult {
+            candidate_index: 0,
+            total_score: 0.0,
+            title_score: 0.0,
+            author_score: 0.0,
+            date_score: 0.0,
+            bonus_score: 0.0,
+            exactness_score: 0.0,
+            proximity_score: 0.0,
+            ranking_bucket: None,
+        };
+        assert_eq!(rule_bucket(RankingRule::ExactDoi, &subject, &matching, &dummy), 1);
+        assert_eq!(rule_bucket(RankingRule::ExactDoi, &subject, &other, &dummy), 0);
+    }
+
+    // Scoring-config loading and validation tests
+    #[test]
+    fn test_validate_config_valid_json() {
+        let raw: RawConfig = serde_json::from_str(
+            r#"{"weights": {"title": 0.5, "author": 0.3, "date": 0.1, "bonus": 0.1},
+                "min_score": 40.0, "top_n": 5,
+                "academic_prefixes": ["reply to"]}"#,
+        )
+        .unwrap();
+        let cfg = validate_config(raw).unwrap();
+        assert!((cfg.title - 0.5).abs() < 0.001);
+        assert_eq!(cfg.top_n, 5);
+        assert_eq!(cfg.academic_prefixes, vec!["reply to".to_string()]);
+    }
+
+    #[test]
+    fn test_validate_config_rejects_out_of_range_weight() {
+        let raw: RawConfig = serde_json::from_str(
+            r#"{"weights": {"title": 5.0, "author": 0.3, "date": 0.1, "bonus": 0.1}}"#,
+        )
+        .unwrap();
+        let err = validate_config(raw).unwrap_err();
+        assert!(err.contains("weights.title"));
+        assert!(err.contains("between 0.0 and 1.0"));
+    }
+
+    #[test]
+    fn test_parse_config_rejects_unknown_field() {
+        let err = parse_config(
+            r#"{"weights": {"title": 0.5, "author": 0.3, "date": 0.1, "bonus": 0.1}, "bogus": 1}"#,
+            false,
+        )
+        .unwrap_err();
+        assert!(err.contains("bogus") || err.contains("unknown field"));
+    }
+
+    #[test]
+    fn test_parse_config_toml() {
+        let toml = "[weights]\ntitle = 0.5\nauthor = 0.3\ndate = 0.1\nbonus = 0.1\n";
+        let cfg = validate_config(parse_config(toml, true).unwrap()).unwrap();
+        assert!((cfg.author - 0.3).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_build_typo_index_recovers_single_typo() {
+        let cand = BibItemData {
+            index: 7,
+            title: "Epistemology and Belief".to_string(),
+            author: "Gettier".to_string(),
+            year: Some(1963),
+            doi: None,
+            journal: None,
+            volume: None,
+            number: None,
+            pages: None,
+            publisher: None,
+            language: None,
+            entry_type: None,
+            editor: None,
+            booktitle: None,
+            series: None,
+            edition: None,
+            institution: None,
+            organization: None,
+            isbn: None,
+            issn: None,
+            address: None,
+            url: None,
+            urldate: None,
+        };
+        let index = build_typo_index(std::slice::from_ref(&cand), 2, true);
+        // A one-letter OCR error on "epistemology" still resolves to candidate 7
+        let mut hits: HashSet<usize> = HashSet::new();
+        for variant in deletion_variants("epistemollogy", typo_budget("epistemollogy".len(), 2)) {
+            if let Some(idxs) = index.get(&variant) {
+                hits.extend(idxs);
+            }
+        }
+        assert!(hits.contains(&7));
+    }
+
+    #[test]
+    fn test_title_algorithm_selection() {
+        assert_eq!(title_algorithm_from(Some("fzf")), TitleAlgorithm::Fzf);
+        assert_eq!(title_algorithm_from(Some("FZF")), TitleAlgorithm::Fzf);
+        assert_eq!(title_algorithm_from(Some("token_sort")), TitleAlgorithm::TokenSort);
+        assert_eq!(title_algorithm_from(None), TitleAlgorithm::TokenSort);
+    }
+
+    // Multi-author list parsing and set-based scoring tests
+    #[test]
+    fn test_split_author_list() {
+        let (people, et_al) = split_author_list("Smith, J. and Doe, A.");
+        assert_eq!(people, vec!["Smith, J.", "Doe, A."]);
+        assert!(!et_al);
+
+        let (people, et_al) = split_author_list("Jane Smith; Alan Doe & Bob Roe");
+        assert_eq!(people, vec!["Jane Smith", "Alan Doe", "Bob Roe"]);
+        assert!(!et_al);
+
+        let (people, et_al) = split_author_list("Jane Smith et al.");
+        assert_eq!(people, vec!["Jane Smith"]);
+        assert!(et_al);
+    }
+
+    #[test]
+    fn test_parse_name_particles_and_comma() {
+        let n = parse_name("van der Berg, Johannes");
+        assert_eq!(n.surname, "van der Berg");
+        assert_eq!(n.given, vec!["Johannes"]);
+
+        let n = parse_name("Ludwig von Mises");
+        assert_eq!(n.surname, "von Mises");
+        assert_eq!(n.given, vec!["Ludwig"]);
+
+        let n = parse_name("Martin Luther King Jr");
+        assert_eq!(n.surname, "King");
+        assert_eq!(n.given, vec!["Martin", "Luther"]);
+    }
+
+    #[test]
+    fn test_score_author_reordered_lists() {
+        // Same two authors, different order, different surface forms still match well
+        let a = score_author("Smith, J. and Doe, A.", "Alan Doe; Jane Smith", 1.0, true);
+        let both_wrong = score_author("Smith, J. and Doe, A.", "Kant; Hegel", 1.0, true);
+        assert!(a > both_wrong);
+        assert!(a > 50.0);
+    }
+
+    #[test]
+    fn test_score_author_et_al_suppresses_penalty() {
+        // A truncated candidate list should not be penalized for missing authors
+        let with_et_al = score_author("Smith, J.", "Jane Smith et al.", 1.0, true);
+        let without = score_author("Smith, J.", "Jane Smith and Alan Doe and Bob Roe", 1.0, true);
+        assert!(with_et_al > without);
+    }
+
+    // Entry-type-aware weight profiles and field routing tests
+    #[test]
+    fn test_entry_type_family() {
+        assert_eq!(entry_type_family("article"), "journal");
+        assert_eq!(entry_type_family("InProceedings"), "proceedings");
+        assert_eq!(entry_type_family("phdthesis"), "thesis");
+        assert_eq!(entry_type_family("misc"), "other");
+    }
+
+    #[test]
+    fn test_types_compatible() {
+        // Same family matches; missing or `other` types never clash
+        assert!(types_compatible(Some("article"), Some("article")));
+        assert!(!types_compatible(Some("article"), Some("book")));
+        assert!(types_compatible(Some("article"), None));
+        assert!(types_compatible(Some("book"), Some("misc")));
+    }
+
+    #[test]
+    fn test_bonus_fields_default_for_type() {
+        // Articles route journal but not publisher; books do the reverse
+        let article = BonusFieldSet::default_for_type("article");
+        assert!(article.journal && !article.publisher);
+
+        let book = BonusFieldSet::default_for_type("book");
+        assert!(book.publisher && !book.journal);
+
+        // Unknown types keep every field enabled
+        let misc = BonusFieldSet::default_for_type("misc");
+        assert!(misc.doi && misc.journal && misc.pages && misc.publisher);
+    }
+
+    #[test]
+    fn test_bonus_fields_from_names() {
+        let set = BonusFieldSet::from_names(&["doi".to_string(), "Publisher".to_string()]);
+        assert!(set.doi && set.publisher);
+        assert!(!set.journal && !set.pages);
+    }
+
+    // Structured bonus fields (editor, booktitle, isbn/issn, ...) tests
+    #[test]
+    fn test_bonus_fields_from_names_structured() {
+        let set = BonusFieldSet::from_names(&[
+            "isbn".to_string(),
+            "organization".to_string(),
+            "urldate".to_string(),
+        ]);
+        assert!(set.isbn_issn && set.institution && set.url);
+        assert!(!set.booktitle && !set.editor);
+    }
+
+    #[test]
+    fn test_bonus_fields_routing_by_type() {
+        // Proceedings papers route booktitle/editor; books route edition
+        let proc = BonusFieldSet::default_for_type("inproceedings");
+        assert!(proc.booktitle && proc.editor && !proc.edition);
+
+        let book = BonusFieldSet::default_for_type("book");
+        assert!(book.edition && book.isbn_issn && !book.booktitle);
+
+        let thesis = BonusFieldSet::default_for_type("phdthesis");
+        assert!(thesis.institution && !thesis.isbn_issn);
+    }
+
+    #[test]
+    fn test_isbn_issn_bonus() {
+        let a = Some("978-0-13-468599-1".to_string());
+        let b = Some("978-0-13-468599-1".to_string());
+        assert!((isbn_issn_bonus(&a, &b) - 100.0).abs() < 0.001);
+        assert!((isbn_issn_bonus(&a, &Some("other".to_string())) - 0.0).abs() < 0.001);
+        assert!((isbn_issn_bonus(&a, &None) - 0.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_exact_and_fuzzy_helpers() {
+        assert!(exact_trimmed(
+            &Some(" http://x ".to_string()),
+            &Some("http://x".to_string())
+        ));
+        assert!(exact_normalized(
+            &Some("2nd".to_string()),
+            &Some("2ND".to_string()),
+            true
+        ));
+        assert!(fuzzy_match(
+            &Some("Handbook of Logic".to_string()),
+            &Some("Logic, Handbook of".to_string()),
+            85.0,
+            true
+        ));
+        assert!(!fuzzy_match(
+            &Some("Logic".to_string()),
+            &Some("Cooking".to_string()),
+            85.0,
+            true
+        ));
+    }
 }